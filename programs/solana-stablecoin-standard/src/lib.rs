@@ -2,18 +2,9 @@
 // SSS-2 Compliant Stablecoin Implementation with Token-2022
 
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::{
-    self,
-    extensions::{
-        transfer_hook::TransferHook,
-        mint::Mint,
-        metadata_pointer::MetadataPointer,
-    },
-    state::Mint,
-};
 
 // Declare program ID (matches Anchor.toml)
-declare_id!("Fg6PaFpoGXkYsnMp2CT5a1k9WkYc2dMkq");
+declare_id!("C1ez3z2tAjAvQabEVPnYeStfYH65842ghYd97Bprw71m");
 
 // Export the main stablecoin module
 pub mod stablecoin;
@@ -65,4 +56,115 @@ pub enum StablecoinError {
     
     #[msg("Transfer hook error")]
     TransferHookError,
+    
+    #[msg("Invalid burn amount")]
+    InvalidBurnAmount,
+    
+    #[msg("Delegated amount does not cover the burn")]
+    InsufficientDelegation,
+    
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    
+    #[msg("Mint exceeds the minter allowance")]
+    MinterAllowanceExceeded,
+    
+    #[msg("Mint exceeds the minter rolling 24h cap")]
+    MinterDailyCapExceeded,
+    
+    #[msg("Stablecoin is paused")]
+    Paused,
+    
+    #[msg("Stablecoin is not paused")]
+    NotPaused,
+    
+    #[msg("No pending authority transfer")]
+    NoPendingAuthority,
+    
+    #[msg("Address is not blacklisted")]
+    AddressNotBlacklisted,
+    
+    #[msg("Invalid metadata field")]
+    InvalidMetadataField,
+    
+    #[msg("Decimals must be between 0 and 9")]
+    InvalidDecimals,
+    
+    #[msg("Address is not allowlisted")]
+    AddressNotAllowlisted,
+    
+    #[msg("KYC expiry must be in the future")]
+    InvalidKycExpiry,
+    
+    #[msg("Batch must contain between 1 and 20 targets")]
+    InvalidBatchSize,
+    
+    #[msg("Remaining accounts do not match the batch targets")]
+    BatchAccountsMismatch,
+    
+    #[msg("Account is not the blacklist entry for this address")]
+    InvalidBlacklistEntry,
+    
+    #[msg("Operation must be queued through a timelocked proposal")]
+    TimelockRequired,
+    
+    #[msg("Timelock has not expired yet")]
+    TimelockNotExpired,
+    
+    #[msg("Timelock must be between 0 seconds and 30 days")]
+    InvalidTimelock,
+    
+    #[msg("Accounts do not match the proposed action")]
+    InvalidProposalAccounts,
+    
+    #[msg("Master admin changed since the action was proposed")]
+    ProposalAdminChanged,
+    
+    #[msg("Proposed action is past its grace period")]
+    ProposalExpired,
+    
+    #[msg("Multisig needs 1 to 10 unique signers and a threshold between 1 and the number of signers")]
+    InvalidMultisig,
+    
+    #[msg("Signer is not part of the multisig")]
+    NotMultisigSigner,
+    
+    #[msg("Proposal has not reached the multisig threshold")]
+    NotEnoughApprovals,
+    
+    #[msg("Multisig signers changed since the proposal was created")]
+    MultisigSignersChanged,
+    
+    #[msg("Proposed instruction exceeds the maximum accounts or data length")]
+    ProposalTooLarge,
+    
+    #[msg("Confidential transfers are not enabled on this mint")]
+    ConfidentialTransfersDisabled,
+    
+    #[msg("Transfer fee cannot exceed 10000 basis points")]
+    InvalidTransferFee,
+    
+    #[msg("Confidential transfers cannot be combined with transfer fees")]
+    UnsupportedExtensionCombination,
+    
+    #[msg("Account is not the configured treasury")]
+    InvalidTreasury,
+    
+    #[msg("Interest-bearing mints need their rate_history PDA")]
+    InvalidRateHistory,
+    
+    #[msg("Minting would exceed the attested reserves")]
+    InsufficientReserves,
+    
+    #[msg("Reserve attestation is older than the staleness window")]
+    StaleReserveAttestation,
+    
+    #[msg("Attestation timestamp is in the future or older than the current one")]
+    InvalidAttestation,
+    
+    #[msg("Reserve staleness window must be positive")]
+    InvalidReserveStaleness,
+    
+    #[msg("Request is no longer pending")]
+    RequestNotPending,
 }
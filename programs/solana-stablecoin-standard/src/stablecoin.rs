@@ -2,31 +2,101 @@
 // Migrating to Token-2022 with Transfer Hook
 
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::{
-    self,
-    extensions::{
-        transfer_hook::TransferHook,
-        mint::Mint,
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+};
+use anchor_lang::system_program;
+use anchor_spl::{
+    token_2022::{self, Token2022},
+    token_interface::{
+        default_account_state_initialize,
+        default_account_state_update,
+        harvest_withheld_tokens_to_mint,
+        interest_bearing_mint_initialize,
+        interest_bearing_mint_update_rate,
+        metadata_pointer_initialize,
+        permanent_delegate_initialize,
+        spl_token_metadata_interface::state::{Field, TokenMetadata},
+        token_metadata_initialize,
+        token_metadata_update_field,
+        transfer_fee_initialize,
+        transfer_fee_set,
+        transfer_hook_initialize,
+        transfer_hook_update,
+        withdraw_withheld_tokens_from_mint,
+        DefaultAccountStateInitialize,
+        DefaultAccountStateUpdate,
+        HarvestWithheldTokensToMint,
+        InterestBearingMintInitialize,
+        InterestBearingMintUpdateRate,
+        MetadataPointerInitialize,
+        Mint,
+        PermanentDelegateInitialize,
+        TokenAccount,
+        TokenMetadataInitialize,
+        TokenMetadataUpdateField,
+        TransferFeeInitialize,
+        TransferFeeSetTransferFee,
+        TransferHookInitialize,
+        TransferHookUpdate,
+        WithdrawWithheldTokensFromMint,
+    },
+};
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta,
+    seeds::Seed,
+    state::ExtraAccountMetaList,
+};
+use spl_token_2022::{
+    extension::{
+        confidential_transfer::{self, ConfidentialTransferAccount, ConfidentialTransferMint},
+        transfer_fee::instruction::withdraw_withheld_tokens_from_accounts,
+        transfer_hook::TransferHookAccount,
+        BaseStateWithExtensions,
+        ExtensionType,
+        StateWithExtensions,
     },
-    state::Mint,
-    instruction::TransferChecked,
+    solana_zk_token_sdk::zk_token_elgamal::pod::ElGamalPubkey,
+    state::AccountState,
 };
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
 
 // Re-export error codes from lib.rs
 use super::StablecoinError;
+use crate::ID;
 
 #[program]
+#[allow(clippy::module_inception)]
 pub mod stablecoin {
     use super::*;
 
     /// Initialize SSS-2 Compliant Stablecoin with Token-2022
-    /// Creates mint with transfer hook for blacklist enforcement
+    /// Creates mint with transfer hook for blacklist enforcement and
+    /// on-mint TokenMetadata so wallets can display the token.
+    /// With `default_account_frozen`, new token accounts start frozen until
+    /// `approve_holder` thaws them. An `auditor_elgamal_pubkey` enables
+    /// confidential transfers, auditable by the holder of that key; encrypted
+    /// balances are beyond `seize_tokens`, which can only freeze them.
+    /// A `transfer_fee` withholds a basis-point fee on every transfer, and an
+    /// `interest_rate` (basis points per year) makes UI amounts accrue yield;
+    /// it needs the `rate_history` PDA, seeded with the initial rate.
+    /// `mint_to` needs a `ReserveAttestation` younger than
+    /// `reserve_staleness_seconds` that covers the new supply
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize(
         ctx: Context<Initialize>,
         name: String,
         symbol: String,
         uri: String,
         decimals: u8,
+        compliance_mode: ComplianceMode,
+        default_account_frozen: bool,
+        auditor_elgamal_pubkey: Option<[u8; 32]>,
+        transfer_fee: Option<TransferFeeParams>,
+        interest_rate: Option<i16>,
+        reserve_staleness_seconds: i64,
     ) -> Result<()> {
         let stablecoin_config = &mut ctx.accounts.stablecoin_config;
         
@@ -34,25 +104,436 @@ pub mod stablecoin {
         require!(name.len() <= 32, StablecoinError::NameTooLong);
         require!(symbol.len() <= 16, StablecoinError::SymbolTooLong);
         require!(uri.len() <= 256, StablecoinError::UriTooLong);
+        require!(decimals <= MAX_DECIMALS, StablecoinError::InvalidDecimals);
+        assert_valid_reserve_staleness(reserve_staleness_seconds)?;
+        if let Some(params) = &transfer_fee {
+            assert_valid_transfer_fee(params.basis_points)?;
+            // Confidential fees would also need ConfidentialTransferFeeConfig
+            require!(
+                auditor_elgamal_pubkey.is_none(),
+                StablecoinError::UnsupportedExtensionCombination
+            );
+        }
         
-        stablecoin_config.name = name;
-        stablecoin_config.symbol = symbol;
-        stablecoin_config.uri = uri;
+        stablecoin_config.name = name.clone();
+        stablecoin_config.symbol = symbol.clone();
+        stablecoin_config.uri = uri.clone();
         stablecoin_config.decimals = decimals;
-        stablecoin_config.mint_authority = ctx.accounts.authority.key();
-        stablecoin_config.freeze_authority = ctx.accounts.authority.key();
+        stablecoin_config.mint = ctx.accounts.mint.key();
+        stablecoin_config.master_admin = ctx.accounts.authority.key();
+        stablecoin_config.pending_master_admin = None;
         stablecoin_config.bump = ctx.bumps.stablecoin_config;
+        stablecoin_config.total_minted = 0;
+        stablecoin_config.total_burned = 0;
+        stablecoin_config.circulating_supply = 0;
+        stablecoin_config.paused = false;
+        stablecoin_config.compliance_mode = compliance_mode;
+        stablecoin_config.rent_collector = ctx.accounts.authority.key();
+        stablecoin_config.timelock_seconds = 0;
+        stablecoin_config.proposal_count = 0;
+        stablecoin_config.treasury = None;
+        stablecoin_config.reserve_staleness_seconds = reserve_staleness_seconds;
+        stablecoin_config.request_count = 0;
+
+        let mut compliance_log = ctx.accounts.compliance_log.load_init()?;
+        compliance_log.stablecoin_config = ctx.accounts.stablecoin_config.key();
+        drop(compliance_log);
+
+        // Create the Token-2022 mint; every extension must be initialized
+        // before InitializeMint2. The config PDA holds every token authority,
+        // so the program's role checks are the only way to exercise them
+        let mint_key = ctx.accounts.mint.key();
+        let config_key = ctx.accounts.stablecoin_config.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"stablecoin",
+            mint_key.as_ref(),
+            &[ctx.bumps.stablecoin_config],
+        ]];
+
+        let mut extensions = vec![
+            ExtensionType::TransferHook,
+            ExtensionType::PermanentDelegate,
+            ExtensionType::MetadataPointer,
+            ExtensionType::DefaultAccountState,
+        ];
+        if auditor_elgamal_pubkey.is_some() {
+            extensions.push(ExtensionType::ConfidentialTransferMint);
+        }
+        if transfer_fee.is_some() {
+            extensions.push(ExtensionType::TransferFeeConfig);
+        }
+        if interest_rate.is_some() {
+            extensions.push(ExtensionType::InterestBearingConfig);
+        }
+        let mint_len =
+            ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&extensions)?;
+
+        // Fund the variable-length TokenMetadata up front; Token-2022
+        // reallocates the mint when it is written
+        let metadata = TokenMetadata {
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            ..Default::default()
+        };
+        let lamports = Rent::get()?.minimum_balance(mint_len + metadata.tlv_size_of()?);
+
+        system_program::create_account(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.mint.to_account_info(),
+                },
+            ),
+            lamports,
+            mint_len as u64,
+            ctx.accounts.token_program.key,
+        )?;
+
+        let token_program_info = ctx.accounts.token_program.to_account_info();
+        let mint_info = ctx.accounts.mint.to_account_info();
+
+        // Every transfer is routed through this program's `transfer_hook`
+        transfer_hook_initialize(
+            CpiContext::new(
+                token_program_info.clone(),
+                TransferHookInitialize {
+                    token_program_id: token_program_info.clone(),
+                    mint: mint_info.clone(),
+                },
+            ),
+            Some(config_key),
+            Some(crate::ID),
+        )?;
+
+        // Lets `seize_tokens` move funds without the holder's signature
+        permanent_delegate_initialize(
+            CpiContext::new(
+                token_program_info.clone(),
+                PermanentDelegateInitialize {
+                    token_program_id: token_program_info.clone(),
+                    mint: mint_info.clone(),
+                },
+            ),
+            &config_key,
+        )?;
+
+        // TokenMetadata lives in the mint account itself
+        metadata_pointer_initialize(
+            CpiContext::new(
+                token_program_info.clone(),
+                MetadataPointerInitialize {
+                    token_program_id: token_program_info.clone(),
+                    mint: mint_info.clone(),
+                },
+            ),
+            Some(config_key),
+            Some(mint_key),
+        )?;
+
+        // Always present so `set_default_account_state` can toggle it later
+        let default_state = if default_account_frozen {
+            AccountState::Frozen
+        } else {
+            AccountState::Initialized
+        };
+        default_account_state_initialize(
+            CpiContext::new(
+                token_program_info.clone(),
+                DefaultAccountStateInitialize {
+                    token_program_id: token_program_info.clone(),
+                    mint: mint_info.clone(),
+                },
+            ),
+            &default_state,
+        )?;
+
+        // New confidential accounts wait for `approve_confidential_account`
+        // until `configure_auto_approve` says otherwise
+        if let Some(auditor_elgamal_pubkey) = auditor_elgamal_pubkey {
+            let ix = confidential_transfer::instruction::initialize_mint(
+                token_program_info.key,
+                &mint_key,
+                Some(config_key),
+                false,
+                Some(ElGamalPubkey(auditor_elgamal_pubkey)),
+            )?;
+            invoke(&ix, &[mint_info.clone(), token_program_info.clone()])?;
+        }
 
-        msg!("Stablecoin initialized with Token-2022: {}", stablecoin_config.symbol);
+        // Fees are withheld in the recipient account until `harvest_fees`
+        // or `withdraw_withheld_fees` sweeps them to the treasury
+        if let Some(params) = transfer_fee {
+            transfer_fee_initialize(
+                CpiContext::new(
+                    token_program_info.clone(),
+                    TransferFeeInitialize {
+                        token_program_id: token_program_info.clone(),
+                        mint: mint_info.clone(),
+                    },
+                ),
+                Some(&config_key),
+                Some(&config_key),
+                params.basis_points,
+                params.maximum_fee,
+            )?;
+        }
+
+        // Balances never rebase; only the UI amount reflects accrued yield
+        if let Some(rate) = interest_rate {
+            interest_bearing_mint_initialize(
+                CpiContext::new(
+                    token_program_info.clone(),
+                    InterestBearingMintInitialize {
+                        token_program_id: token_program_info.clone(),
+                        mint: mint_info.clone(),
+                    },
+                ),
+                Some(config_key),
+                rate,
+            )?;
+        }
+
+        token_2022::initialize_mint2(
+            CpiContext::new(
+                token_program_info.clone(),
+                token_2022::InitializeMint2 {
+                    mint: mint_info.clone(),
+                },
+            ),
+            decimals,
+            &config_key,
+            Some(&config_key),
+        )?;
+
+        token_metadata_initialize(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataInitialize {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: mint_info.clone(),
+                    update_authority: ctx.accounts.stablecoin_config.to_account_info(),
+                    mint_authority: ctx.accounts.stablecoin_config.to_account_info(),
+                    mint: mint_info,
+                },
+                signer_seeds,
+            ),
+            name,
+            symbol,
+            uri,
+        )?;
+
+        if let Some(rate) = interest_rate {
+            let rate_history_info = ctx
+                .accounts
+                .rate_history
+                .as_ref()
+                .ok_or(StablecoinError::InvalidRateHistory)?
+                .to_account_info();
+            let (expected, bump) = Pubkey::find_program_address(
+                &[b"rate_history", config_key.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(
+                rate_history_info.key(),
+                expected,
+                StablecoinError::InvalidRateHistory
+            );
+
+            let rate_history_seeds: &[&[&[u8]]] =
+                &[&[b"rate_history", config_key.as_ref(), &[bump]]];
+            create_pda_account(
+                &rate_history_info,
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                RATE_HISTORY_SPACE,
+                rate_history_seeds,
+            )?;
+
+            let clock = Clock::get()?;
+            let mut rate_history = RateHistory {
+                stablecoin_config: config_key,
+                total_updates: 0,
+                records: Vec::new(),
+                bump,
+            };
+            rate_history.append(RateRecord {
+                rate,
+                updated_by: ctx.accounts.authority.key(),
+                slot: clock.slot,
+                unix_timestamp: clock.unix_timestamp,
+            });
+            rate_history.try_serialize(&mut &mut rate_history_info.try_borrow_mut_data()?[..])?;
+        }
+
+        emit!(ConfigInitialized {
+            mint: mint_key,
+            actor: ctx.accounts.authority.key(),
+            target: ctx.accounts.stablecoin_config.key(),
+            decimals,
+            slot: Clock::get()?.slot,
+        });
         Ok(())
     }
-        stablecoin_config.uri = uri;
-        stablecoin_config.decimals = decimals;
-        stablecoin_config.mint_authority = ctx.accounts.authority.key();
-        stablecoin_config.freeze_authority = ctx.accounts.authority.key();
-        stablecoin_config.bump = ctx.bumps.stablecoin_config;
 
-        msg!("Stablecoin initialized with Token-2022: {}", stablecoin_config.symbol);
+    /// Update the on-mint TokenMetadata (metadata admin only)
+    /// `additional_metadata` upserts arbitrary fields such as `issuer` or
+    /// `attestation_url`
+    pub fn update_metadata(
+        ctx: Context<UpdateMetadata>,
+        name: Option<String>,
+        symbol: Option<String>,
+        uri: Option<String>,
+        additional_metadata: Vec<MetadataField>,
+    ) -> Result<()> {
+        let mut updates: Vec<(Field, String)> = Vec::new();
+        if let Some(name) = name {
+            require!(name.len() <= 32, StablecoinError::NameTooLong);
+            updates.push((Field::Name, name));
+        }
+        if let Some(symbol) = symbol {
+            require!(symbol.len() <= 16, StablecoinError::SymbolTooLong);
+            updates.push((Field::Symbol, symbol));
+        }
+        if let Some(uri) = uri {
+            require!(uri.len() <= 256, StablecoinError::UriTooLong);
+            updates.push((Field::Uri, uri));
+        }
+        for field in additional_metadata {
+            require!(
+                !field.key.is_empty() && field.key.len() <= 32,
+                StablecoinError::InvalidMetadataField
+            );
+            require!(field.value.len() <= 256, StablecoinError::InvalidMetadataField);
+            updates.push((Field::Key(field.key), field.value));
+        }
+
+        // Size the mint for the updated metadata before Token-2022 reallocates it
+        let mint_info = ctx.accounts.mint.to_account_info();
+        let new_len = {
+            let data = mint_info.try_borrow_data()?;
+            let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+            let mut metadata = mint.get_variable_len_extension::<TokenMetadata>()?;
+            let current_size = metadata.tlv_size_of()?;
+            for (field, value) in updates.iter() {
+                metadata.update(field.clone(), value.clone());
+            }
+            data.len() - current_size + metadata.tlv_size_of()?
+        };
+        top_up_rent(
+            &mint_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            new_len,
+        )?;
+
+        let mint_key = ctx.accounts.mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"stablecoin",
+            mint_key.as_ref(),
+            &[ctx.accounts.stablecoin_config.bump],
+        ]];
+
+        for (field, value) in updates {
+            token_metadata_update_field(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    TokenMetadataUpdateField {
+                        token_program_id: ctx.accounts.token_program.to_account_info(),
+                        metadata: mint_info.clone(),
+                        update_authority: ctx.accounts.stablecoin_config.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                field.clone(),
+                value.clone(),
+            )?;
+
+            // Keep the config copy in sync for programs reading it
+            let stablecoin_config = &mut ctx.accounts.stablecoin_config;
+            match field {
+                Field::Name => stablecoin_config.name = value,
+                Field::Symbol => stablecoin_config.symbol = value,
+                Field::Uri => stablecoin_config.uri = value,
+                Field::Key(_) => {}
+            }
+        }
+
+        emit!(MetadataUpdated {
+            mint: mint_key,
+            actor: ctx.accounts.authority.key(),
+            target: mint_key,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Create the ExtraAccountMetaList for the mint's transfer hook
+    /// Must be sent right after `initialize` so Token-2022 can resolve the
+    /// blacklist PDAs of both transfer parties
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.stablecoin_config.master_admin,
+            StablecoinError::UnauthorizedAccess
+        );
+
+        let extra_account_metas = InitializeExtraAccountMetaList::extra_account_metas()?;
+        let mut data = ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?;
+        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &extra_account_metas)?;
+
+        emit!(TransferHookInitialized {
+            mint: ctx.accounts.mint.key(),
+            actor: ctx.accounts.authority.key(),
+            target: ctx.accounts.extra_account_meta_list.key(),
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Transfer Hook entry point (SPL transfer-hook `Execute`)
+    /// Invoked by Token-2022 on every transfer; rejects it while the
+    /// stablecoin is paused or when either party fails the compliance mode
+    /// (blacklisted, or not allowlisted). Confidential transfers do not reach
+    /// it: they are covered by the auditor key, `approve_confidential_account`
+    /// and freezing the accounts of blacklisted holders
+    #[interface(spl_transfer_hook_interface::execute)]
+    pub fn transfer_hook(ctx: Context<ExecuteTransferHook>, _amount: u64) -> Result<()> {
+        assert_is_transferring(&ctx.accounts.source_token.to_account_info())?;
+        require!(!ctx.accounts.stablecoin_config.paused, StablecoinError::Paused);
+
+        // Seizures are signed by the config PDA as permanent delegate and
+        // must go through even though the source is blacklisted
+        if ctx.accounts.owner.key() == ctx.accounts.stablecoin_config.key() {
+            return Ok(());
+        }
+
+        match ctx.accounts.stablecoin_config.compliance_mode {
+            ComplianceMode::Open => {}
+            ComplianceMode::Blacklist => {
+                require!(
+                    !is_blacklisted(&ctx.accounts.source_blacklist_entry)?,
+                    StablecoinError::AddressBlacklisted
+                );
+                require!(
+                    !is_blacklisted(&ctx.accounts.destination_blacklist_entry)?,
+                    StablecoinError::AddressBlacklisted
+                );
+            }
+            ComplianceMode::Allowlist => {
+                require!(
+                    is_allowlisted(&ctx.accounts.source_allowlist_entry)?,
+                    StablecoinError::AddressNotAllowlisted
+                );
+                require!(
+                    is_allowlisted(&ctx.accounts.destination_allowlist_entry)?,
+                    StablecoinError::AddressNotAllowlisted
+                );
+            }
+        }
+
         Ok(())
     }
 
@@ -62,64 +543,196 @@ pub mod stablecoin {
         ctx: Context<MintTo>,
         amount: u64,
     ) -> Result<()> {
-        let cpi_accounts = token_2022::instruction::MintTo {
-            token_program: ctx.accounts.token_program.to_account_info(),
+        let clock = Clock::get()?;
+        mint_checked(
+            MintAccounts {
+                stablecoin_config: &mut ctx.accounts.stablecoin_config,
+                minter_info: &mut ctx.accounts.minter_info,
+                mint: &ctx.accounts.mint,
+                to: &ctx.accounts.to,
+                allowlist_entry: &ctx.accounts.allowlist_entry,
+                reserve_attestation: &ctx.accounts.reserve_attestation,
+                token_program: &ctx.accounts.token_program,
+            },
+            amount,
+            clock.unix_timestamp,
+        )?;
+        
+        emit!(Minted {
+            mint: ctx.accounts.mint.key(),
+            actor: ctx.accounts.authority.key(),
+            target: ctx.accounts.to.key(),
+            amount,
+            slot: clock.slot,
+        });
+        Ok(())
+    }
+
+    /// Burn tokens from the signer's own account (holder redemption)
+    pub fn burn(
+        ctx: Context<BurnTokens>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, StablecoinError::InvalidBurnAmount);
+        require!(!ctx.accounts.stablecoin_config.paused, StablecoinError::Paused);
+
+        let cpi_accounts = token_2022::Burn {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.from.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+        };
+
+        token_2022::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+            ),
+            amount,
+        )?;
+
+        record_burn(&mut ctx.accounts.stablecoin_config, amount)?;
+
+        emit!(Burned {
+            mint: ctx.accounts.mint.key(),
+            actor: ctx.accounts.owner.key(),
+            target: ctx.accounts.from.key(),
+            amount,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Burn tokens from a holder account (issuer redemption)
+    /// The holder must first approve the authority as delegate for `amount`
+    pub fn burn_from(
+        ctx: Context<BurnFrom>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, StablecoinError::InvalidBurnAmount);
+        require!(!ctx.accounts.stablecoin_config.paused, StablecoinError::Paused);
+        require!(
+            ctx.accounts.from.delegate == COption::Some(ctx.accounts.authority.key())
+                && ctx.accounts.from.delegated_amount >= amount,
+            StablecoinError::InsufficientDelegation
+        );
+
+        let cpi_accounts = token_2022::Burn {
             mint: ctx.accounts.mint.to_account_info(),
-            to: ctx.accounts.to.to_account_info(),
+            from: ctx.accounts.from.to_account_info(),
             authority: ctx.accounts.authority.to_account_info(),
         };
-        
-        token_2022::mint_to(
+
+        token_2022::burn(
             CpiContext::new(
                 ctx.accounts.token_program.to_account_info(),
                 cpi_accounts,
             ),
             amount,
         )?;
-        
-        msg!("Minted {} tokens to {}", amount, ctx.accounts.to.key());
+
+        record_burn(&mut ctx.accounts.stablecoin_config, amount)?;
+
+        emit!(Burned {
+            mint: ctx.accounts.mint.key(),
+            actor: ctx.accounts.authority.key(),
+            target: ctx.accounts.from.key(),
+            amount,
+            slot: Clock::get()?.slot,
+        });
         Ok(())
     }
 
     /// Freeze a token account (compliance)
     /// Uses Token-2022 Freeze extension
-    pub fn freeze_account(ctx: Context<FreezeAccount>) -> Result<()> {
-        let cpi_accounts = token_2022::instruction::FreezeAccount {
-            token_program: ctx.accounts.token_program.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
+    pub fn freeze_account(
+        ctx: Context<FreezeAccount>,
+        reason_code: Option<u16>,
+        case_reference: Option<[u8; 32]>,
+    ) -> Result<()> {
+        let mint_key = ctx.accounts.mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"stablecoin",
+            mint_key.as_ref(),
+            &[ctx.accounts.stablecoin_config.bump],
+        ]];
+
+        let cpi_accounts = token_2022::FreezeAccount {
             account: ctx.accounts.token_account.to_account_info(),
-            authority: ctx.accounts.authority.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.stablecoin_config.to_account_info(),
         };
         
         token_2022::freeze_account(
-            CpiContext::new(
+            CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
                 cpi_accounts,
+                signer_seeds,
             ),
         )?;
+
+        let clock = Clock::get()?;
+        ctx.accounts.compliance_log.load_mut()?.append(
+            ComplianceAction::Freeze,
+            ctx.accounts.authority.key(),
+            ctx.accounts.token_account.key(),
+            0,
+            reason_code.unwrap_or_default(),
+            case_reference.unwrap_or_default(),
+            &clock,
+        );
         
-        msg!("Froze account {}", ctx.accounts.token_account.key());
+        emit!(Frozen {
+            mint: mint_key,
+            actor: ctx.accounts.authority.key(),
+            target: ctx.accounts.token_account.key(),
+            reason_code,
+            case_reference,
+            slot: clock.slot,
+        });
         Ok(())
     }
 
     /// Thaw a frozen token account
     /// Uses Token-2022 Thaw extension
     pub fn thaw_account(ctx: Context<ThawAccount>) -> Result<()> {
-        let cpi_accounts = token_2022::instruction::ThawAccount {
-            token_program: ctx.accounts.token_program.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
+        let mint_key = ctx.accounts.mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"stablecoin",
+            mint_key.as_ref(),
+            &[ctx.accounts.stablecoin_config.bump],
+        ]];
+
+        let cpi_accounts = token_2022::ThawAccount {
             account: ctx.accounts.token_account.to_account_info(),
-            authority: ctx.accounts.authority.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.stablecoin_config.to_account_info(),
         };
         
         token_2022::thaw_account(
-            CpiContext::new(
+            CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
                 cpi_accounts,
+                signer_seeds,
             ),
         )?;
+
+        let clock = Clock::get()?;
+        ctx.accounts.compliance_log.load_mut()?.append(
+            ComplianceAction::Thaw,
+            ctx.accounts.authority.key(),
+            ctx.accounts.token_account.key(),
+            0,
+            0,
+            [0; 32],
+            &clock,
+        );
         
-        msg!("Thawed account {}", ctx.accounts.token_account.key());
+        emit!(Thawed {
+            mint: mint_key,
+            actor: ctx.accounts.authority.key(),
+            target: ctx.accounts.token_account.key(),
+            slot: clock.slot,
+        });
         Ok(())
     }
 
@@ -127,275 +740,4338 @@ pub mod stablecoin {
     /// Stores blacklist status in PDA for Transfer Hook
     pub fn add_to_blacklist(
         ctx: Context<ModifyBlacklist>, 
-        address: Pubkey
+        address: Pubkey,
+        reason_code: Option<u16>,
+        case_reference: Option<[u8; 32]>,
     ) -> Result<()> {
+        let clock = Clock::get()?;
         let blacklist_entry = &mut ctx.accounts.blacklist_entry;
-        
-        // Verify authority
-        require!(
-            ctx.accounts.authority.key() == ctx.accounts.stablecoin_config.mint_authority,
-            StablecoinError::UnauthorizedAccess
-        );
 
         blacklist_entry.address = address;
         blacklist_entry.is_blacklisted = true;
-        blacklist_entry.timestamp = Clock::get()?.unix_timestamp;
+        blacklist_entry.timestamp = clock.unix_timestamp;
+        blacklist_entry.reason_code = reason_code;
+        blacklist_entry.case_reference = case_reference;
 
-        msg!("Added address {} to blacklist (for Transfer Hook)", address);
+        ctx.accounts.compliance_log.load_mut()?.append(
+            ComplianceAction::Blacklist,
+            ctx.accounts.authority.key(),
+            address,
+            0,
+            reason_code.unwrap_or_default(),
+            case_reference.unwrap_or_default(),
+            &clock,
+        );
+
+        emit!(Blacklisted {
+            mint: ctx.accounts.mint.key(),
+            actor: ctx.accounts.authority.key(),
+            target: address,
+            reason_code,
+            case_reference,
+            slot: clock.slot,
+        });
         Ok(())
     }
 
-    /// Remove an address from blacklist
+    /// Remove an address from blacklist. The entry must already exist; the
+    /// cleared account stays open until `close_blacklist_entry` reclaims it
     pub fn remove_from_blacklist(
-        ctx: Context<ModifyBlacklist>, 
+        ctx: Context<RemoveFromBlacklist>, 
         address: Pubkey
     ) -> Result<()> {
+        let clock = Clock::get()?;
         let blacklist_entry = &mut ctx.accounts.blacklist_entry;
-        
-        // Verify authority
-        require!(
-            ctx.accounts.authority.key() == ctx.accounts.stablecoin_config.mint_authority,
-            StablecoinError::UnauthorizedAccess
-        );
 
-        blacklist_entry.address = address;
         blacklist_entry.is_blacklisted = false;
-        blacklist_entry.timestamp = Clock::get()?.unix_timestamp;
+        blacklist_entry.timestamp = clock.unix_timestamp;
+        blacklist_entry.reason_code = None;
+        blacklist_entry.case_reference = None;
 
-        msg!("Removed address {} from blacklist", address);
+        ctx.accounts.compliance_log.load_mut()?.append(
+            ComplianceAction::Unblacklist,
+            ctx.accounts.authority.key(),
+            address,
+            0,
+            0,
+            [0; 32],
+            &clock,
+        );
+
+        emit!(Unblacklisted {
+            mint: ctx.accounts.mint.key(),
+            actor: ctx.accounts.authority.key(),
+            target: address,
+            slot: clock.slot,
+        });
         Ok(())
     }
 
-    /// Seize tokens from a blacklisted account (compliance)
-    /// Freeze + Transfer to treasury
-    pub fn seize_tokens(
-        ctx: Context<SeizeTokens>,
-        amount: u64,
+    /// Close the blacklist entry of a removed address and reclaim its rent
+    /// Only entries that are no longer blacklisted can be closed; the history
+    /// stays in the compliance log and events
+    pub fn close_blacklist_entry(
+        ctx: Context<CloseBlacklistEntry>,
+        address: Pubkey,
     ) -> Result<()> {
-        // First freeze the account
-        let freeze_cpi = token_2022::instruction::FreezeAccount {
-            token_program: ctx.accounts.token_program.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
-            account: ctx.accounts.from.to_account_info(),
-            authority: ctx.accounts.authority.to_account_info(),
-        };
-        
-        token_2022::freeze_account(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                freeze_cpi,
-            ),
-        )?;
-
-        // Then transfer to treasury
-        let transfer_cpi = token_2022::instruction::TransferChecked {
-            token_program: ctx.accounts.token_program.to_account_info(),
-            mint: ctx.accounts.mint.to_account_info(),
-            from: ctx.accounts.from.to_account_info(),
-            to: ctx.accounts.treasury.to_account_info(),
-            authority: ctx.accounts.from_authority.to_account_info(),
-            amount: ctx.accounts.amount,
-            decimals: ctx.accounts.mint.decimals,
-        };
-        
-        token_2022::transfer_checked(
-            CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                transfer_cpi,
-            ),
-            amount,
-        )?;
+        require!(
+            !ctx.accounts.blacklist_entry.is_blacklisted,
+            StablecoinError::AddressBlacklisted
+        );
 
-        msg!("Seized {} tokens from {} to treasury", amount, ctx.accounts.from.key());
+        emit!(BlacklistEntryClosed {
+            mint: ctx.accounts.mint.key(),
+            actor: ctx.accounts.authority.key(),
+            target: address,
+            rent_collector: ctx.accounts.rent_collector.key(),
+            slot: Clock::get()?.slot,
+        });
         Ok(())
     }
 
-    /// Update mint authority (multi-sig compatible)
-    pub fn update_minter(
-        ctx: Context<UpdateAuthority>,
-        new_mint_authority: Pubkey,
+    /// Blacklist up to `MAX_BATCH_SIZE` addresses in one instruction
+    /// `remaining_accounts[i]` must be the blacklist PDA of `addresses[i]`;
+    /// missing entries are created and funded by `payer`
+    pub fn batch_add_to_blacklist<'info>(
+        ctx: Context<'_, '_, '_, 'info, BatchModifyBlacklist<'info>>,
+        addresses: Vec<Pubkey>,
+        reason_code: Option<u16>,
+        case_reference: Option<[u8; 32]>,
     ) -> Result<()> {
-        let stablecoin_config = &mut ctx.accounts.stablecoin_config;
-        
-        require!(
-            ctx.accounts.authority.key() == stablecoin_config.mint_authority,
-            StablecoinError::UnauthorizedAccess
-        );
+        assert_batch(&addresses, ctx.remaining_accounts)?;
+
+        let clock = Clock::get()?;
+        let mint_key = ctx.accounts.mint.key();
+        let config_key = ctx.accounts.stablecoin_config.key();
+        let mut compliance_log = ctx.accounts.compliance_log.load_mut()?;
+
+        for (address, entry_info) in addresses.iter().zip(ctx.remaining_accounts) {
+            let mut entry = load_or_create_blacklist_entry(
+                entry_info,
+                &config_key,
+                address,
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+            )?;
+            entry.address = *address;
+            entry.is_blacklisted = true;
+            entry.timestamp = clock.unix_timestamp;
+            entry.reason_code = reason_code;
+            entry.case_reference = case_reference;
+            entry.try_serialize(&mut &mut entry_info.try_borrow_mut_data()?[..])?;
+
+            compliance_log.append(
+                ComplianceAction::Blacklist,
+                ctx.accounts.authority.key(),
+                *address,
+                0,
+                reason_code.unwrap_or_default(),
+                case_reference.unwrap_or_default(),
+                &clock,
+            );
 
-        stablecoin_config.mint_authority = new_mint_authority;
-        msg!("Updated mint authority to {}", new_mint_authority);
+            emit!(Blacklisted {
+                mint: mint_key,
+                actor: ctx.accounts.authority.key(),
+                target: *address,
+                reason_code,
+                case_reference,
+                slot: clock.slot,
+            });
+        }
         Ok(())
     }
 
-    /// Update freeze authority (multi-sig compatible)
-    pub fn update_freezer(
-        ctx: Context<UpdateAuthority>,
-        new_freeze_authority: Pubkey,
+    /// Remove up to `MAX_BATCH_SIZE` addresses from the blacklist
+    /// `remaining_accounts[i]` must be the existing blacklist PDA of `addresses[i]`
+    pub fn batch_remove_from_blacklist<'info>(
+        ctx: Context<'_, '_, '_, 'info, BatchModifyBlacklist<'info>>,
+        addresses: Vec<Pubkey>,
     ) -> Result<()> {
-        let stablecoin_config = &mut ctx.accounts.stablecoin_config;
-        
-        require!(
-            ctx.accounts.authority.key() == stablecoin_config.freeze_authority,
-            StablecoinError::UnauthorizedAccess
-        );
+        assert_batch(&addresses, ctx.remaining_accounts)?;
+
+        let clock = Clock::get()?;
+        let mint_key = ctx.accounts.mint.key();
+        let config_key = ctx.accounts.stablecoin_config.key();
+        let mut compliance_log = ctx.accounts.compliance_log.load_mut()?;
+
+        for (address, entry_info) in addresses.iter().zip(ctx.remaining_accounts) {
+            assert_blacklist_entry_address(entry_info, &config_key, address)?;
+            require!(
+                !entry_info.data_is_empty() && entry_info.owner == &crate::ID,
+                StablecoinError::AddressNotBlacklisted
+            );
 
-        stablecoin_config.freeze_authority = new_freeze_authority;
-        msg!("Updated freeze authority to {}", new_freeze_authority);
+            let mut entry = BlacklistEntry::try_deserialize(&mut &entry_info.try_borrow_data()?[..])?;
+            entry.is_blacklisted = false;
+            entry.timestamp = clock.unix_timestamp;
+            entry.reason_code = None;
+            entry.case_reference = None;
+            entry.try_serialize(&mut &mut entry_info.try_borrow_mut_data()?[..])?;
+
+            compliance_log.append(
+                ComplianceAction::Unblacklist,
+                ctx.accounts.authority.key(),
+                *address,
+                0,
+                0,
+                [0; 32],
+                &clock,
+            );
+
+            emit!(Unblacklisted {
+                mint: mint_key,
+                actor: ctx.accounts.authority.key(),
+                target: *address,
+                slot: clock.slot,
+            });
+        }
         Ok(())
     }
-}
 
-// ============================================================================
-// Contexts (Account Validation)
-// ============================================================================
+    /// Freeze up to `MAX_BATCH_SIZE` token accounts in one instruction
+    /// `remaining_accounts[i]` must be the token account `targets[i]` of this
+    /// mint; accounts that are already frozen are skipped
+    pub fn batch_freeze<'info>(
+        ctx: Context<'_, '_, '_, 'info, BatchFreeze<'info>>,
+        targets: Vec<Pubkey>,
+        reason_code: Option<u16>,
+        case_reference: Option<[u8; 32]>,
+    ) -> Result<()> {
+        assert_batch(&targets, ctx.remaining_accounts)?;
 
-#[derive(Accounts)]
-#[instruction(name: String, symbol: String, uri: String, decimals: u8, transfer_hook_program: Option<Pubkey>)]
-pub struct Initialize<'info> {
-    #[account(
-        init,
-        payer = payer,
-        space = 8 + 32 + 16 + 256 + 1 + 32 + 32 + 1, // discriminator + strings + decimals + authorities + bump
-        seeds = [b"stablecoin", mint.key().as_ref()],
-        bump
-    )]
-    pub stablecoin_config: Account<'info, StablecoinConfig>,
+        let mint_key = ctx.accounts.mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"stablecoin",
+            mint_key.as_ref(),
+            &[ctx.accounts.stablecoin_config.bump],
+        ]];
 
-    #[account(
-        init,
-        payer = payer,
-        space = 32 + 1, // program key + discriminator
-        seeds = [b"transfer_hook", transfer_hook_program.as_ref()],
-        bump
-    )]
-    pub transfer_hook_program: UncheckedAccount<'info>,
+        let clock = Clock::get()?;
+        let mut compliance_log = ctx.accounts.compliance_log.load_mut()?;
 
-    #[account(
-        init,
-        payer = payer,
-        mint::decimals = 9,
-        mint::authority = authority,
-        // NOVO: Configurar mint com hook program se fornecido
-        extension::transfer_hook::TransferHook {
-            program: transfer_hook_program,
-            authority: mint_authority,
-        },
-    )]
-    pub mint: Account<'info, Mint>,
+        for (target, account_info) in targets.iter().zip(ctx.remaining_accounts) {
+            require_keys_eq!(account_info.key(), *target, StablecoinError::InvalidTokenAccount);
+            require_keys_eq!(
+                *account_info.owner,
+                ctx.accounts.token_program.key(),
+                StablecoinError::InvalidTokenAccount
+            );
+            let is_frozen = {
+                let data = account_info.try_borrow_data()?;
+                let token_account =
+                    StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+                require_keys_eq!(
+                    token_account.base.mint,
+                    mint_key,
+                    StablecoinError::InvalidTokenAccount
+                );
+                token_account.base.is_frozen()
+            };
+            if is_frozen {
+                continue;
+            }
 
-    #[account(mut)]
-    pub transfer_hook_program: UncheckedAccount<'info>,
+            token_2022::freeze_account(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token_2022::FreezeAccount {
+                        account: account_info.clone(),
+                        mint: ctx.accounts.mint.to_account_info(),
+                        authority: ctx.accounts.stablecoin_config.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+            )?;
 
-    #[account(mut)]
-    pub authority: Signer<'info>,
+            compliance_log.append(
+                ComplianceAction::Freeze,
+                ctx.accounts.authority.key(),
+                *target,
+                0,
+                reason_code.unwrap_or_default(),
+                case_reference.unwrap_or_default(),
+                &clock,
+            );
 
-    #[account(mut)]
-    pub payer: Signer<'info>,
+            emit!(Frozen {
+                mint: mint_key,
+                actor: ctx.accounts.authority.key(),
+                target: *target,
+                reason_code,
+                case_reference,
+                slot: clock.slot,
+            });
+        }
+        Ok(())
+    }
 
-    pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, token_2022::ID>,
-}
+    /// Add an address to the allowlist (KYC'd holder)
+    /// Only enforced while the stablecoin runs in `ComplianceMode::Allowlist`
+    pub fn add_to_allowlist(
+        ctx: Context<ModifyAllowlist>,
+        address: Pubkey,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        let allowlist_entry = &mut ctx.accounts.allowlist_entry;
 
-#[derive(Accounts)]
-pub struct MintTo<'info> {
-    #[account(mut)]
-    pub mint: Account<'info, Mint>,
+        allowlist_entry.address = address;
+        allowlist_entry.is_allowed = true;
+        allowlist_entry.timestamp = clock.unix_timestamp;
 
-    #[account(
-        init,
-        payer = payer,
-        token::mint = mint,
-        token::authority = authority,
-    )]
-    pub to: Account<'info, TokenAccount>,
+        ctx.accounts.compliance_log.load_mut()?.append(
+            ComplianceAction::Allowlist,
+            ctx.accounts.authority.key(),
+            address,
+            0,
+            0,
+            [0; 32],
+            &clock,
+        );
 
-    #[account(mut)]
-    pub authority: Signer<'info>,
+        emit!(Allowlisted {
+            mint: ctx.accounts.mint.key(),
+            actor: ctx.accounts.authority.key(),
+            target: address,
+            slot: clock.slot,
+        });
+        Ok(())
+    }
 
-    #[account(mut)]
-    pub payer: Signer<'info>,
+    /// Remove an address from the allowlist
+    pub fn remove_from_allowlist(
+        ctx: Context<ModifyAllowlist>,
+        address: Pubkey,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        let allowlist_entry = &mut ctx.accounts.allowlist_entry;
 
-    pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, token_2022::ID>,
-}
+        allowlist_entry.address = address;
+        allowlist_entry.is_allowed = false;
+        allowlist_entry.timestamp = clock.unix_timestamp;
 
-#[derive(Accounts)]
-pub struct FreezeAccount<'info> {
-    #[account(mut)]
-    pub mint: Account<'info, Mint>,
+        ctx.accounts.compliance_log.load_mut()?.append(
+            ComplianceAction::Unallowlist,
+            ctx.accounts.authority.key(),
+            address,
+            0,
+            0,
+            [0; 32],
+            &clock,
+        );
 
-    #[account(mut)]
-    pub token_account: Account<'info, TokenAccount>,
+        emit!(Unallowlisted {
+            mint: ctx.accounts.mint.key(),
+            actor: ctx.accounts.authority.key(),
+            target: address,
+            slot: clock.slot,
+        });
+        Ok(())
+    }
 
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    /// Record a holder's KYC approval and thaw the given token account
+    /// Used with DefaultAccountState=Frozen, where new accounts cannot
+    /// transact until onboarded. The thaw is decided per token account, so
+    /// approving a holder's second account or re-approving after expiry both
+    /// work; blacklisted holders are refused, which keeps compliance freezes
+    /// in place. Re-approving refreshes the record
+    pub fn approve_holder(
+        ctx: Context<ApproveHolder>,
+        kyc_tier: u8,
+        jurisdiction: [u8; 2],
+        expiry: i64,
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        require!(expiry > clock.unix_timestamp, StablecoinError::InvalidKycExpiry);
+        require!(
+            !is_blacklisted(&ctx.accounts.blacklist_entry)?,
+            StablecoinError::AddressBlacklisted
+        );
 
-    pub token_program: Program<'info, token_2022::ID>,
-}
+        let holder = ctx.accounts.token_account.owner;
+        let holder_record = &mut ctx.accounts.holder_record;
+        holder_record.stablecoin_config = ctx.accounts.stablecoin_config.key();
+        holder_record.owner = holder;
+        holder_record.kyc_tier = kyc_tier;
+        holder_record.jurisdiction = jurisdiction;
+        holder_record.expiry = expiry;
+        holder_record.approved_by = ctx.accounts.authority.key();
+        holder_record.timestamp = clock.unix_timestamp;
+        holder_record.bump = ctx.bumps.holder_record;
 
-#[derive(Accounts)]
-pub struct ThawAccount<'info> {
-    #[account(mut)]
-    pub mint: Account<'info, Mint>,
+        if ctx.accounts.token_account.is_frozen() {
+            let mint_key = ctx.accounts.mint.key();
+            let signer_seeds: &[&[&[u8]]] = &[&[
+                b"stablecoin",
+                mint_key.as_ref(),
+                &[ctx.accounts.stablecoin_config.bump],
+            ]];
 
-    #[account(mut)]
-    pub token_account: Account<'info, TokenAccount>,
+            token_2022::thaw_account(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token_2022::ThawAccount {
+                        account: ctx.accounts.token_account.to_account_info(),
+                        mint: ctx.accounts.mint.to_account_info(),
+                        authority: ctx.accounts.stablecoin_config.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+            )?;
+        }
 
-    #[account(mut)]
-    pub authority: Signer<'info>,
+        ctx.accounts.compliance_log.load_mut()?.append(
+            ComplianceAction::ApproveHolder,
+            ctx.accounts.authority.key(),
+            holder,
+            0,
+            0,
+            [0; 32],
+            &clock,
+        );
 
-    pub token_program: Program<'info, token_2022::ID>,
-}
+        emit!(HolderApproved {
+            mint: ctx.accounts.mint.key(),
+            actor: ctx.accounts.authority.key(),
+            target: holder,
+            token_account: ctx.accounts.token_account.key(),
+            kyc_tier,
+            jurisdiction,
+            expiry,
+            slot: clock.slot,
+        });
+        Ok(())
+    }
+
+    /// Toggle whether new confidential transfer accounts are approved
+    /// automatically (master admin only); the auditor key is kept.
+    /// Queued through `propose_action` while the timelock is on
+    pub fn configure_auto_approve(
+        ctx: Context<ConfigureConfidentialTransfers>,
+        auto_approve_new_accounts: bool,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.stablecoin_config.master_admin,
+            StablecoinError::UnauthorizedAccess
+        );
+        assert_no_timelock(&ctx.accounts.stablecoin_config)?;
+
+        update_confidential_auto_approve(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.stablecoin_config,
+            auto_approve_new_accounts,
+        )?;
+
+        emit!(ConfidentialAutoApproveChanged {
+            mint: ctx.accounts.mint.key(),
+            actor: ctx.accounts.authority.key(),
+            auto_approve_new_accounts,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Let a token account use confidential balances (allowlister only)
+    /// Required for every account while auto-approve is off
+    pub fn approve_confidential_account(ctx: Context<ApproveConfidentialAccount>) -> Result<()> {
+        let mint_key = ctx.accounts.mint.key();
+        let config_key = ctx.accounts.stablecoin_config.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"stablecoin",
+            mint_key.as_ref(),
+            &[ctx.accounts.stablecoin_config.bump],
+        ]];
+
+        let ix = confidential_transfer::instruction::approve_account(
+            ctx.accounts.token_program.key,
+            &ctx.accounts.token_account.key(),
+            &mint_key,
+            &config_key,
+            &[],
+        )?;
+        invoke_signed(
+            &ix,
+            &[
+                ctx.accounts.token_account.to_account_info(),
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.stablecoin_config.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+
+        let clock = Clock::get()?;
+        ctx.accounts.compliance_log.load_mut()?.append(
+            ComplianceAction::ApproveConfidentialAccount,
+            ctx.accounts.authority.key(),
+            ctx.accounts.token_account.key(),
+            0,
+            0,
+            [0; 32],
+            &clock,
+        );
+
+        emit!(ConfidentialAccountApproved {
+            mint: mint_key,
+            actor: ctx.accounts.authority.key(),
+            target: ctx.accounts.token_account.key(),
+            slot: clock.slot,
+        });
+        Ok(())
+    }
+
+    /// Change the transfer fee (fee admin only)
+    /// Token-2022 applies the new fee two epochs later
+    pub fn update_transfer_fee(
+        ctx: Context<UpdateTransferFee>,
+        basis_points: u16,
+        maximum_fee: u64,
+    ) -> Result<()> {
+        assert_valid_transfer_fee(basis_points)?;
+
+        let mint_key = ctx.accounts.mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"stablecoin",
+            mint_key.as_ref(),
+            &[ctx.accounts.stablecoin_config.bump],
+        ]];
+
+        transfer_fee_set(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferFeeSetTransferFee {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    authority: ctx.accounts.stablecoin_config.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            basis_points,
+            maximum_fee,
+        )?;
+
+        emit!(TransferFeeUpdated {
+            mint: mint_key,
+            actor: ctx.accounts.authority.key(),
+            basis_points,
+            maximum_fee,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Set the token account that receives swept transfer fees (master admin only)
+    /// Queued through `propose_action` while the timelock is on
+    pub fn set_treasury(ctx: Context<SetTreasury>) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.stablecoin_config.master_admin,
+            StablecoinError::UnauthorizedAccess
+        );
+        assert_no_timelock(&ctx.accounts.stablecoin_config)?;
+
+        let treasury = ctx.accounts.treasury.key();
+        ctx.accounts.stablecoin_config.treasury = Some(treasury);
+
+        emit!(TreasuryChanged {
+            mint: ctx.accounts.mint.key(),
+            actor: ctx.accounts.authority.key(),
+            target: treasury,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Harvest withheld fees from the token accounts passed as remaining
+    /// accounts into the mint, then withdraw everything the mint holds to
+    /// the treasury (fee admin only)
+    pub fn harvest_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, SweepFees<'info>>,
+    ) -> Result<()> {
+        require!(
+            ctx.remaining_accounts.len() <= MAX_BATCH_SIZE,
+            StablecoinError::InvalidBatchSize
+        );
+
+        let mint_key = ctx.accounts.mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"stablecoin",
+            mint_key.as_ref(),
+            &[ctx.accounts.stablecoin_config.bump],
+        ]];
+
+        if !ctx.remaining_accounts.is_empty() {
+            harvest_withheld_tokens_to_mint(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    HarvestWithheldTokensToMint {
+                        token_program_id: ctx.accounts.token_program.to_account_info(),
+                        mint: ctx.accounts.mint.to_account_info(),
+                    },
+                ),
+                ctx.remaining_accounts.to_vec(),
+            )?;
+        }
+
+        let balance_before = ctx.accounts.treasury.amount;
+        withdraw_withheld_tokens_from_mint(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                WithdrawWithheldTokensFromMint {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    destination: ctx.accounts.treasury.to_account_info(),
+                    authority: ctx.accounts.stablecoin_config.to_account_info(),
+                },
+                signer_seeds,
+            ),
+        )?;
+
+        ctx.accounts.treasury.reload()?;
+        emit!(FeesWithdrawn {
+            mint: mint_key,
+            actor: ctx.accounts.authority.key(),
+            target: ctx.accounts.treasury.key(),
+            amount: ctx.accounts.treasury.amount - balance_before,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Withdraw withheld fees straight from the token accounts passed as
+    /// remaining accounts to the treasury (fee admin only)
+    /// Unlike `harvest_fees` this also works on frozen accounts
+    pub fn withdraw_withheld_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, SweepFees<'info>>,
+    ) -> Result<()> {
+        require!(
+            !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() <= MAX_BATCH_SIZE,
+            StablecoinError::InvalidBatchSize
+        );
+
+        let mint_key = ctx.accounts.mint.key();
+        let config_key = ctx.accounts.stablecoin_config.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"stablecoin",
+            mint_key.as_ref(),
+            &[ctx.accounts.stablecoin_config.bump],
+        ]];
+
+        let sources: Vec<&Pubkey> = ctx.remaining_accounts.iter().map(|account| account.key).collect();
+        let ix = withdraw_withheld_tokens_from_accounts(
+            ctx.accounts.token_program.key,
+            &mint_key,
+            &ctx.accounts.treasury.key(),
+            &config_key,
+            &[],
+            &sources,
+        )?;
+
+        let mut account_infos = vec![
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.treasury.to_account_info(),
+            ctx.accounts.stablecoin_config.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+        ];
+        account_infos.extend_from_slice(ctx.remaining_accounts);
+
+        let balance_before = ctx.accounts.treasury.amount;
+        invoke_signed(&ix, &account_infos, signer_seeds)?;
+
+        ctx.accounts.treasury.reload()?;
+        emit!(FeesWithdrawn {
+            mint: mint_key,
+            actor: ctx.accounts.authority.key(),
+            target: ctx.accounts.treasury.key(),
+            amount: ctx.accounts.treasury.amount - balance_before,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Publish the latest proof-of-reserves attestation (attestor only)
+    /// `attested_at` is the report's as-of time and cannot go backwards
+    pub fn attest_reserves(
+        ctx: Context<AttestReserves>,
+        reserves: u64,
+        attested_at: i64,
+        report_hash: [u8; 32],
+    ) -> Result<()> {
+        let clock = Clock::get()?;
+        let reserve_attestation = &mut ctx.accounts.reserve_attestation;
+        require!(
+            attested_at <= clock.unix_timestamp && attested_at >= reserve_attestation.attested_at,
+            StablecoinError::InvalidAttestation
+        );
+
+        reserve_attestation.stablecoin_config = ctx.accounts.stablecoin_config.key();
+        reserve_attestation.reserves = reserves;
+        reserve_attestation.attested_at = attested_at;
+        reserve_attestation.report_hash = report_hash;
+        reserve_attestation.attestor = ctx.accounts.authority.key();
+        reserve_attestation.slot = clock.slot;
+        reserve_attestation.bump = ctx.bumps.reserve_attestation;
+
+        emit!(ReservesAttested {
+            mint: ctx.accounts.stablecoin_config.mint,
+            actor: ctx.accounts.authority.key(),
+            reserves,
+            attested_at,
+            report_hash,
+            slot: clock.slot,
+        });
+        Ok(())
+    }
+
+    /// Set how old the reserve attestation may be when minting (master admin
+    /// only). Queued through `propose_action` while the timelock is on
+    pub fn set_reserve_staleness(
+        ctx: Context<UpdateAuthority>,
+        reserve_staleness_seconds: i64,
+    ) -> Result<()> {
+        let stablecoin_config = &mut ctx.accounts.stablecoin_config;
+
+        require!(
+            ctx.accounts.authority.key() == stablecoin_config.master_admin,
+            StablecoinError::UnauthorizedAccess
+        );
+        assert_no_timelock(stablecoin_config)?;
+        assert_valid_reserve_staleness(reserve_staleness_seconds)?;
+
+        stablecoin_config.reserve_staleness_seconds = reserve_staleness_seconds;
+
+        emit!(ReserveStalenessChanged {
+            mint: stablecoin_config.mint,
+            actor: ctx.accounts.authority.key(),
+            reserve_staleness_seconds,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Change the interest rate, in basis points per year (rate authority only)
+    /// Every change is kept in the stablecoin's `RateHistory`
+    pub fn update_rate(
+        ctx: Context<UpdateRate>,
+        rate: i16,
+    ) -> Result<()> {
+        let mint_key = ctx.accounts.mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"stablecoin",
+            mint_key.as_ref(),
+            &[ctx.accounts.stablecoin_config.bump],
+        ]];
+
+        interest_bearing_mint_update_rate(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                InterestBearingMintUpdateRate {
+                    token_program_id: ctx.accounts.token_program.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    rate_authority: ctx.accounts.stablecoin_config.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            rate,
+        )?;
+
+        let clock = Clock::get()?;
+        ctx.accounts.rate_history.append(RateRecord {
+            rate,
+            updated_by: ctx.accounts.authority.key(),
+            slot: clock.slot,
+            unix_timestamp: clock.unix_timestamp,
+        });
+
+        emit!(RateUpdated {
+            mint: mint_key,
+            actor: ctx.accounts.authority.key(),
+            rate,
+            slot: clock.slot,
+        });
+        Ok(())
+    }
+
+    /// Toggle whether new token accounts start frozen (master admin only)
+    /// Existing accounts are unaffected. Queued through `propose_action`
+    /// while the timelock is on
+    pub fn set_default_account_state(
+        ctx: Context<SetDefaultAccountState>,
+        frozen: bool,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.stablecoin_config.master_admin,
+            StablecoinError::UnauthorizedAccess
+        );
+        assert_no_timelock(&ctx.accounts.stablecoin_config)?;
+
+        update_default_account_state(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.stablecoin_config,
+            frozen,
+        )?;
+
+        emit!(DefaultAccountStateChanged {
+            mint: ctx.accounts.mint.key(),
+            actor: ctx.accounts.authority.key(),
+            frozen,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Change where the rent of closed blacklist entries goes (master admin only)
+    /// Queued through `propose_action` while the timelock is on
+    pub fn set_rent_collector(
+        ctx: Context<UpdateAuthority>,
+        rent_collector: Pubkey,
+    ) -> Result<()> {
+        let stablecoin_config = &mut ctx.accounts.stablecoin_config;
+
+        require!(
+            ctx.accounts.authority.key() == stablecoin_config.master_admin,
+            StablecoinError::UnauthorizedAccess
+        );
+        assert_no_timelock(stablecoin_config)?;
+
+        stablecoin_config.rent_collector = rent_collector;
+
+        emit!(RentCollectorChanged {
+            mint: stablecoin_config.mint,
+            actor: ctx.accounts.authority.key(),
+            target: rent_collector,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Switch between Open, Blacklist and Allowlist enforcement (master admin only)
+    /// Queued through `propose_action` while the timelock is on
+    pub fn set_compliance_mode(
+        ctx: Context<UpdateAuthority>,
+        compliance_mode: ComplianceMode,
+    ) -> Result<()> {
+        let stablecoin_config = &mut ctx.accounts.stablecoin_config;
+
+        require!(
+            ctx.accounts.authority.key() == stablecoin_config.master_admin,
+            StablecoinError::UnauthorizedAccess
+        );
+        assert_no_timelock(stablecoin_config)?;
+
+        stablecoin_config.compliance_mode = compliance_mode;
+
+        emit!(ComplianceModeChanged {
+            mint: stablecoin_config.mint,
+            actor: ctx.accounts.authority.key(),
+            compliance_mode,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Seize tokens from a blacklisted account (compliance)
+    /// Moves funds to treasury through the PermanentDelegate (the config PDA),
+    /// so the holder's signature is not needed; the source is left frozen.
+    /// A confidential balance cannot be moved this way: it stays frozen with
+    /// the account and is reported through `ConfidentialBalanceFrozen`.
+    /// Transfer hook accounts must be passed as remaining accounts
+    pub fn seize_tokens<'info>(
+        ctx: Context<'_, '_, '_, 'info, SeizeTokens<'info>>,
+        amount: u64,
+        reason_code: Option<u16>,
+        case_reference: Option<[u8; 32]>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.stablecoin_config.paused, StablecoinError::Paused);
+        require!(
+            ctx.accounts.blacklist_entry.is_blacklisted,
+            StablecoinError::AddressNotBlacklisted
+        );
+
+        let mint_key = ctx.accounts.mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"stablecoin",
+            mint_key.as_ref(),
+            &[ctx.accounts.stablecoin_config.bump],
+        ]];
+
+        // A frozen account cannot send, so thaw it for the transfer
+        if ctx.accounts.from.is_frozen() {
+            let thaw_cpi = token_2022::ThawAccount {
+                account: ctx.accounts.from.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: ctx.accounts.stablecoin_config.to_account_info(),
+            };
+
+            token_2022::thaw_account(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    thaw_cpi,
+                    signer_seeds,
+                ),
+            )?;
+        }
+
+        // Transfer to treasury as permanent delegate
+        spl_token_2022::onchain::invoke_transfer_checked(
+            ctx.accounts.token_program.key,
+            ctx.accounts.from.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.treasury.to_account_info(),
+            ctx.accounts.stablecoin_config.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            ctx.accounts.stablecoin_config.decimals,
+            signer_seeds,
+        )?;
+
+        // Then (re)freeze the account
+        let freeze_cpi = token_2022::FreezeAccount {
+            account: ctx.accounts.from.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.stablecoin_config.to_account_info(),
+        };
+        
+        token_2022::freeze_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                freeze_cpi,
+                signer_seeds,
+            ),
+        )?;
+
+        let clock = Clock::get()?;
+        if has_confidential_balance(&ctx.accounts.from.to_account_info())? {
+            emit!(ConfidentialBalanceFrozen {
+                mint: mint_key,
+                actor: ctx.accounts.authority.key(),
+                target: ctx.accounts.from.key(),
+                slot: clock.slot,
+            });
+        }
+
+        ctx.accounts.compliance_log.load_mut()?.append(
+            ComplianceAction::Seize,
+            ctx.accounts.authority.key(),
+            ctx.accounts.from.key(),
+            amount,
+            reason_code.unwrap_or_default(),
+            case_reference.unwrap_or_default(),
+            &clock,
+        );
+
+        emit!(Seized {
+            mint: mint_key,
+            actor: ctx.accounts.authority.key(),
+            target: ctx.accounts.from.key(),
+            destination: ctx.accounts.treasury.key(),
+            amount,
+            reason_code,
+            case_reference,
+            slot: clock.slot,
+        });
+        Ok(())
+    }
+
+    /// Configure a minter's allowance and optional rolling 24h cap
+    /// Overwrites the remaining allowance, so it also refills it
+    /// (master minter only, like USDC `configureMinter`)
+    pub fn configure_minter(
+        ctx: Context<ConfigureMinter>,
+        minter: Pubkey,
+        allowance: u64,
+        daily_cap: u64,
+    ) -> Result<()> {
+        let minter_info = &mut ctx.accounts.minter_info;
+
+        // Quota increases go through `propose_action` while the timelock is on
+        require!(
+            ctx.accounts.stablecoin_config.timelock_seconds == 0
+                || !minter_info.is_quota_increase(allowance, daily_cap),
+            StablecoinError::TimelockRequired
+        );
+
+        if minter_info.minter == Pubkey::default() {
+            minter_info.stablecoin_config = ctx.accounts.stablecoin_config.key();
+            minter_info.minter = minter;
+            minter_info.bump = ctx.bumps.minter_info;
+        }
+        minter_info.allowance = allowance;
+        minter_info.daily_cap = daily_cap;
+
+        emit!(MinterConfigured {
+            mint: ctx.accounts.stablecoin_config.mint,
+            actor: ctx.accounts.authority.key(),
+            target: minter,
+            allowance,
+            daily_cap,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Remove a minter's quota account (master minter only)
+    /// The minter can no longer mint even while holding the Minter role
+    pub fn remove_minter(
+        ctx: Context<RemoveMinter>,
+        minter: Pubkey,
+    ) -> Result<()> {
+        emit!(MinterRemoved {
+            mint: ctx.accounts.stablecoin_config.mint,
+            actor: ctx.accounts.authority.key(),
+            target: minter,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Emergency stop: halts minting, burning, seizure and all transfers
+    pub fn pause(ctx: Context<SetPaused>) -> Result<()> {
+        let stablecoin_config = &mut ctx.accounts.stablecoin_config;
+        require!(!stablecoin_config.paused, StablecoinError::Paused);
+
+        stablecoin_config.paused = true;
+
+        emit!(PauseChanged {
+            mint: stablecoin_config.mint,
+            actor: ctx.accounts.authority.key(),
+            paused: true,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Lift the emergency stop
+    /// Goes through `propose_action` instead while the timelock is on
+    pub fn unpause(ctx: Context<SetPaused>) -> Result<()> {
+        let stablecoin_config = &mut ctx.accounts.stablecoin_config;
+        require!(stablecoin_config.paused, StablecoinError::NotPaused);
+        assert_no_timelock(stablecoin_config)?;
+
+        stablecoin_config.paused = false;
+
+        emit!(PauseChanged {
+            mint: stablecoin_config.mint,
+            actor: ctx.accounts.authority.key(),
+            paused: false,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Grant a role to a holder (master admin only)
+    /// Creates the `[b"role", config, role_id, holder]` PDA checked by
+    /// every privileged instruction. Queued through `propose_action` while
+    /// the timelock is on; revocation stays immediate
+    pub fn grant_role(
+        ctx: Context<GrantRole>,
+        role: Role,
+        holder: Pubkey,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.stablecoin_config.master_admin,
+            StablecoinError::UnauthorizedAccess
+        );
+        assert_no_timelock(&ctx.accounts.stablecoin_config)?;
+
+        let clock = Clock::get()?;
+        let role_grant = &mut ctx.accounts.role_grant;
+        role_grant.stablecoin_config = ctx.accounts.stablecoin_config.key();
+        role_grant.role = role;
+        role_grant.holder = holder;
+        role_grant.granted_by = ctx.accounts.authority.key();
+        role_grant.timestamp = clock.unix_timestamp;
+        role_grant.bump = ctx.bumps.role_grant;
+
+        emit!(RoleGranted {
+            mint: ctx.accounts.stablecoin_config.mint,
+            actor: ctx.accounts.authority.key(),
+            target: holder,
+            role,
+            slot: clock.slot,
+        });
+        Ok(())
+    }
+
+    /// Revoke a role from a holder (master admin only)
+    /// Closes the role PDA and returns its rent to the master admin
+    pub fn revoke_role(
+        ctx: Context<RevokeRole>,
+        role: Role,
+        holder: Pubkey,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.stablecoin_config.master_admin,
+            StablecoinError::UnauthorizedAccess
+        );
+
+        emit!(RoleRevoked {
+            mint: ctx.accounts.stablecoin_config.mint,
+            actor: ctx.accounts.authority.key(),
+            target: holder,
+            role,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Nominate a new master admin, e.g. the `AdminMultisig` PDA
+    /// Nothing changes until the nominee signs `accept_authority`
+    /// Goes through `propose_action` instead while the timelock is on
+    pub fn propose_authority(
+        ctx: Context<UpdateAuthority>,
+        new_master_admin: Pubkey,
+    ) -> Result<()> {
+        let stablecoin_config = &mut ctx.accounts.stablecoin_config;
+        
+        require!(
+            ctx.accounts.authority.key() == stablecoin_config.master_admin,
+            StablecoinError::UnauthorizedAccess
+        );
+        assert_no_timelock(stablecoin_config)?;
+
+        stablecoin_config.pending_master_admin = Some(new_master_admin);
+
+        emit!(AuthorityTransferProposed {
+            mint: stablecoin_config.mint,
+            actor: ctx.accounts.authority.key(),
+            target: new_master_admin,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Complete the handover; must be signed by the nominee
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        let stablecoin_config = &mut ctx.accounts.stablecoin_config;
+
+        let pending_master_admin = stablecoin_config
+            .pending_master_admin
+            .ok_or(StablecoinError::NoPendingAuthority)?;
+        require!(
+            ctx.accounts.new_authority.key() == pending_master_admin,
+            StablecoinError::UnauthorizedAccess
+        );
+
+        let previous_master_admin = stablecoin_config.master_admin;
+        stablecoin_config.master_admin = pending_master_admin;
+        stablecoin_config.pending_master_admin = None;
+
+        emit!(AuthorityChanged {
+            mint: stablecoin_config.mint,
+            actor: previous_master_admin,
+            target: pending_master_admin,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Withdraw a pending nomination (current master admin only)
+    pub fn cancel_authority_transfer(ctx: Context<UpdateAuthority>) -> Result<()> {
+        let stablecoin_config = &mut ctx.accounts.stablecoin_config;
+
+        require!(
+            ctx.accounts.authority.key() == stablecoin_config.master_admin,
+            StablecoinError::UnauthorizedAccess
+        );
+        require!(
+            stablecoin_config.pending_master_admin.is_some(),
+            StablecoinError::NoPendingAuthority
+        );
+
+        let cancelled_master_admin = stablecoin_config.pending_master_admin.take();
+
+        emit!(AuthorityTransferCancelled {
+            mint: stablecoin_config.mint,
+            actor: ctx.accounts.authority.key(),
+            target: cancelled_master_admin.unwrap_or_default(),
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Turn on the governance timelock (master admin only)
+    /// Once enabled, changing the delay is itself a `GovernanceAction::SetTimelock`
+    pub fn set_timelock(
+        ctx: Context<UpdateAuthority>,
+        timelock_seconds: i64,
+    ) -> Result<()> {
+        let stablecoin_config = &mut ctx.accounts.stablecoin_config;
+
+        require!(
+            ctx.accounts.authority.key() == stablecoin_config.master_admin,
+            StablecoinError::UnauthorizedAccess
+        );
+        assert_no_timelock(stablecoin_config)?;
+        assert_valid_timelock(timelock_seconds)?;
+
+        stablecoin_config.timelock_seconds = timelock_seconds;
+
+        emit!(TimelockChanged {
+            mint: stablecoin_config.mint,
+            actor: ctx.accounts.authority.key(),
+            timelock_seconds,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Queue a sensitive operation (master admin only)
+    /// The same master admin executes it between `eta` and
+    /// `eta + PROPOSAL_GRACE_SECONDS`; a guardian can cancel it until then
+    pub fn propose_action(
+        ctx: Context<ProposeAction>,
+        action: GovernanceAction,
+    ) -> Result<()> {
+        let stablecoin_config = &mut ctx.accounts.stablecoin_config;
+
+        require!(
+            ctx.accounts.authority.key() == stablecoin_config.master_admin,
+            StablecoinError::UnauthorizedAccess
+        );
+        match action {
+            GovernanceAction::SetTimelock { timelock_seconds } => {
+                assert_valid_timelock(timelock_seconds)?;
+            }
+            GovernanceAction::SetReserveStaleness { reserve_staleness_seconds } => {
+                assert_valid_reserve_staleness(reserve_staleness_seconds)?;
+            }
+            _ => {}
+        }
+
+        let clock = Clock::get()?;
+        let eta = clock
+            .unix_timestamp
+            .checked_add(stablecoin_config.timelock_seconds)
+            .ok_or(StablecoinError::MathOverflow)?;
+        let action_id = stablecoin_config.proposal_count;
+        stablecoin_config.proposal_count = action_id
+            .checked_add(1)
+            .ok_or(StablecoinError::MathOverflow)?;
+
+        let proposed_action = &mut ctx.accounts.proposed_action;
+        proposed_action.stablecoin_config = stablecoin_config.key();
+        proposed_action.id = action_id;
+        proposed_action.action = action.clone();
+        proposed_action.master_admin = stablecoin_config.master_admin;
+        proposed_action.payer = ctx.accounts.payer.key();
+        proposed_action.created_at = clock.unix_timestamp;
+        proposed_action.eta = eta;
+        proposed_action.bump = ctx.bumps.proposed_action;
+
+        emit!(ActionProposed {
+            mint: stablecoin_config.mint,
+            actor: ctx.accounts.authority.key(),
+            action_id,
+            action,
+            eta,
+            slot: clock.slot,
+        });
+        Ok(())
+    }
+
+    /// Apply a queued action whose timelock has expired (master admin only)
+    /// Proposals queued by a previous master admin cannot execute.
+    /// `ConfigureMinter` needs the minter's existing `minter_info`,
+    /// `GrantRole` the uninitialized `role_grant` PDA and `SetTreasury` the
+    /// new `treasury` token account
+    pub fn execute_action(
+        ctx: Context<ExecuteAction>,
+        action_id: u64,
+    ) -> Result<()> {
+        let master_admin = ctx.accounts.stablecoin_config.master_admin;
+        require!(
+            ctx.accounts.authority.key() == master_admin,
+            StablecoinError::UnauthorizedAccess
+        );
+        require!(
+            ctx.accounts.proposed_action.master_admin == master_admin,
+            StablecoinError::ProposalAdminChanged
+        );
+
+        let clock = Clock::get()?;
+        let eta = ctx.accounts.proposed_action.eta;
+        require!(
+            clock.unix_timestamp >= eta,
+            StablecoinError::TimelockNotExpired
+        );
+        require!(
+            clock.unix_timestamp <= eta.saturating_add(PROPOSAL_GRACE_SECONDS),
+            StablecoinError::ProposalExpired
+        );
+
+        let action = ctx.accounts.proposed_action.action.clone();
+        let mint_key = ctx.accounts.mint.key();
+        let actor = ctx.accounts.authority.key();
+
+        match action {
+            GovernanceAction::TransferAuthority { new_master_admin } => {
+                // The nominee still has to `accept_authority`
+                ctx.accounts.stablecoin_config.pending_master_admin = Some(new_master_admin);
+
+                emit!(AuthorityTransferProposed {
+                    mint: mint_key,
+                    actor,
+                    target: new_master_admin,
+                    slot: clock.slot,
+                });
+            }
+            GovernanceAction::ConfigureMinter { minter, allowance, daily_cap } => {
+                let config_key = ctx.accounts.stablecoin_config.key();
+                let minter_info = ctx
+                    .accounts
+                    .minter_info
+                    .as_mut()
+                    .ok_or(StablecoinError::InvalidProposalAccounts)?;
+                require!(
+                    minter_info.stablecoin_config == config_key && minter_info.minter == minter,
+                    StablecoinError::InvalidProposalAccounts
+                );
+
+                minter_info.allowance = allowance;
+                minter_info.daily_cap = daily_cap;
+
+                emit!(MinterConfigured {
+                    mint: mint_key,
+                    actor,
+                    target: minter,
+                    allowance,
+                    daily_cap,
+                    slot: clock.slot,
+                });
+            }
+            GovernanceAction::UpdateTransferHookProgram { program_id } => {
+                let signer_seeds: &[&[&[u8]]] = &[&[
+                    b"stablecoin",
+                    mint_key.as_ref(),
+                    &[ctx.accounts.stablecoin_config.bump],
+                ]];
+
+                transfer_hook_update(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        TransferHookUpdate {
+                            token_program_id: ctx.accounts.token_program.to_account_info(),
+                            mint: ctx.accounts.mint.to_account_info(),
+                            authority: ctx.accounts.stablecoin_config.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    program_id,
+                )?;
+
+                emit!(TransferHookProgramUpdated {
+                    mint: mint_key,
+                    actor,
+                    program_id,
+                    slot: clock.slot,
+                });
+            }
+            GovernanceAction::Unpause => {
+                let stablecoin_config = &mut ctx.accounts.stablecoin_config;
+                require!(stablecoin_config.paused, StablecoinError::NotPaused);
+                stablecoin_config.paused = false;
+
+                emit!(PauseChanged {
+                    mint: mint_key,
+                    actor,
+                    paused: false,
+                    slot: clock.slot,
+                });
+            }
+            GovernanceAction::SetTimelock { timelock_seconds } => {
+                ctx.accounts.stablecoin_config.timelock_seconds = timelock_seconds;
+
+                emit!(TimelockChanged {
+                    mint: mint_key,
+                    actor,
+                    timelock_seconds,
+                    slot: clock.slot,
+                });
+            }
+            GovernanceAction::GrantRole { role, holder } => {
+                let config_key = ctx.accounts.stablecoin_config.key();
+                let role_grant_info = ctx
+                    .accounts
+                    .role_grant
+                    .as_ref()
+                    .ok_or(StablecoinError::InvalidProposalAccounts)?
+                    .to_account_info();
+                let (expected, bump) = Pubkey::find_program_address(
+                    &[b"role", config_key.as_ref(), &[role as u8], holder.as_ref()],
+                    &crate::ID,
+                );
+                require_keys_eq!(
+                    role_grant_info.key(),
+                    expected,
+                    StablecoinError::InvalidProposalAccounts
+                );
+                require!(
+                    role_grant_info.data_is_empty(),
+                    StablecoinError::InvalidProposalAccounts
+                );
+
+                let signer_seeds: &[&[&[u8]]] = &[&[
+                    b"role",
+                    config_key.as_ref(),
+                    &[role as u8],
+                    holder.as_ref(),
+                    &[bump],
+                ]];
+                create_pda_account(
+                    &role_grant_info,
+                    &ctx.accounts.authority.to_account_info(),
+                    &ctx.accounts.system_program.to_account_info(),
+                    ROLE_GRANT_SPACE,
+                    signer_seeds,
+                )?;
+
+                let role_grant = RoleGrant {
+                    stablecoin_config: config_key,
+                    role,
+                    holder,
+                    granted_by: actor,
+                    timestamp: clock.unix_timestamp,
+                    bump,
+                };
+                role_grant.try_serialize(&mut &mut role_grant_info.try_borrow_mut_data()?[..])?;
+
+                emit!(RoleGranted {
+                    mint: mint_key,
+                    actor,
+                    target: holder,
+                    role,
+                    slot: clock.slot,
+                });
+            }
+            GovernanceAction::SetComplianceMode { compliance_mode } => {
+                ctx.accounts.stablecoin_config.compliance_mode = compliance_mode;
+
+                emit!(ComplianceModeChanged {
+                    mint: mint_key,
+                    actor,
+                    compliance_mode,
+                    slot: clock.slot,
+                });
+            }
+            GovernanceAction::SetDefaultAccountState { frozen } => {
+                update_default_account_state(
+                    &ctx.accounts.token_program.to_account_info(),
+                    &ctx.accounts.mint.to_account_info(),
+                    &ctx.accounts.stablecoin_config,
+                    frozen,
+                )?;
+
+                emit!(DefaultAccountStateChanged {
+                    mint: mint_key,
+                    actor,
+                    frozen,
+                    slot: clock.slot,
+                });
+            }
+            GovernanceAction::SetRentCollector { rent_collector } => {
+                ctx.accounts.stablecoin_config.rent_collector = rent_collector;
+
+                emit!(RentCollectorChanged {
+                    mint: mint_key,
+                    actor,
+                    target: rent_collector,
+                    slot: clock.slot,
+                });
+            }
+            GovernanceAction::SetTreasury { treasury } => {
+                let treasury_account = ctx
+                    .accounts
+                    .treasury
+                    .as_ref()
+                    .ok_or(StablecoinError::InvalidProposalAccounts)?;
+                require_keys_eq!(
+                    treasury_account.key(),
+                    treasury,
+                    StablecoinError::InvalidProposalAccounts
+                );
+                ctx.accounts.stablecoin_config.treasury = Some(treasury);
+
+                emit!(TreasuryChanged {
+                    mint: mint_key,
+                    actor,
+                    target: treasury,
+                    slot: clock.slot,
+                });
+            }
+            GovernanceAction::SetReserveStaleness { reserve_staleness_seconds } => {
+                ctx.accounts.stablecoin_config.reserve_staleness_seconds = reserve_staleness_seconds;
+
+                emit!(ReserveStalenessChanged {
+                    mint: mint_key,
+                    actor,
+                    reserve_staleness_seconds,
+                    slot: clock.slot,
+                });
+            }
+            GovernanceAction::SetConfidentialAutoApprove { auto_approve_new_accounts } => {
+                update_confidential_auto_approve(
+                    &ctx.accounts.token_program.to_account_info(),
+                    &ctx.accounts.mint.to_account_info(),
+                    &ctx.accounts.stablecoin_config,
+                    auto_approve_new_accounts,
+                )?;
+
+                emit!(ConfidentialAutoApproveChanged {
+                    mint: mint_key,
+                    actor,
+                    auto_approve_new_accounts,
+                    slot: clock.slot,
+                });
+            }
+        }
+
+        emit!(ActionExecuted {
+            mint: mint_key,
+            actor,
+            action_id,
+            slot: clock.slot,
+        });
+        Ok(())
+    }
+
+    /// Drop a queued action before it executes (guardian only)
+    pub fn cancel_action(
+        ctx: Context<CancelAction>,
+        action_id: u64,
+    ) -> Result<()> {
+        emit!(ActionCancelled {
+            mint: ctx.accounts.stablecoin_config.mint,
+            actor: ctx.accounts.authority.key(),
+            action_id,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Create the built-in M-of-N admin multisig (master admin only)
+    /// The multisig PDA signs the instructions it executes, so it can hold
+    /// roles or become the master admin itself
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.authority.key() == ctx.accounts.stablecoin_config.master_admin,
+            StablecoinError::UnauthorizedAccess
+        );
+        assert_valid_multisig(&signers, threshold)?;
+
+        let multisig = &mut ctx.accounts.multisig;
+        multisig.stablecoin_config = ctx.accounts.stablecoin_config.key();
+        multisig.signers = signers.clone();
+        multisig.threshold = threshold;
+        multisig.nonce = 0;
+        multisig.owner_set_seqno = 0;
+        multisig.bump = ctx.bumps.multisig;
+
+        emit!(MultisigSignersChanged {
+            mint: ctx.accounts.stablecoin_config.mint,
+            actor: ctx.accounts.authority.key(),
+            target: multisig.key(),
+            signers,
+            threshold,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Replace the signer set and threshold
+    /// Must be executed by the multisig itself; pending proposals are invalidated
+    pub fn set_multisig_signers(
+        ctx: Context<SetMultisigSigners>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        assert_valid_multisig(&signers, threshold)?;
+
+        let multisig = &mut ctx.accounts.multisig;
+        multisig.signers = signers.clone();
+        multisig.threshold = threshold;
+        multisig.owner_set_seqno = multisig
+            .owner_set_seqno
+            .checked_add(1)
+            .ok_or(StablecoinError::MathOverflow)?;
+
+        emit!(MultisigSignersChanged {
+            mint: ctx.accounts.stablecoin_config.mint,
+            actor: multisig.key(),
+            target: multisig.key(),
+            signers,
+            threshold,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Propose a call to this program, to be signed by the multisig PDA
+    /// The proposer must be a multisig signer and counts as the first approval
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        accounts: Vec<MultisigAccountMeta>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+        let signer_index = multisig.signer_index(&ctx.accounts.proposer.key())?;
+        let nonce = multisig.nonce;
+        multisig.nonce = nonce.checked_add(1).ok_or(StablecoinError::MathOverflow)?;
+
+        let mut approvals = vec![false; multisig.signers.len()];
+        approvals[signer_index] = true;
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.multisig = multisig.key();
+        proposal.nonce = nonce;
+        proposal.owner_set_seqno = multisig.owner_set_seqno;
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.accounts = accounts;
+        proposal.data = data;
+        proposal.approvals = approvals;
+        proposal.bump = ctx.bumps.proposal;
+
+        emit!(MultisigProposalCreated {
+            mint: ctx.accounts.stablecoin_config.mint,
+            actor: ctx.accounts.proposer.key(),
+            target: multisig.key(),
+            nonce,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Approve a pending multisig proposal (multisig signers only)
+    pub fn approve_proposal(
+        ctx: Context<ApproveProposal>,
+        nonce: u64,
+    ) -> Result<()> {
+        let multisig = &ctx.accounts.multisig;
+        let proposal = &mut ctx.accounts.proposal;
+        require!(
+            proposal.owner_set_seqno == multisig.owner_set_seqno,
+            StablecoinError::MultisigSignersChanged
+        );
+
+        let signer_index = multisig.signer_index(&ctx.accounts.signer.key())?;
+        proposal.approvals[signer_index] = true;
+
+        emit!(MultisigProposalApproved {
+            mint: ctx.accounts.stablecoin_config.mint,
+            actor: ctx.accounts.signer.key(),
+            target: multisig.key(),
+            nonce,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// Execute a proposal that reached the threshold (multisig signers only)
+    /// Every account of the proposed instruction, and this program, must be
+    /// passed as remaining accounts
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
+        nonce: u64,
+    ) -> Result<()> {
+        let multisig = &ctx.accounts.multisig;
+        let proposal = &ctx.accounts.proposal;
+        multisig.signer_index(&ctx.accounts.signer.key())?;
+        require!(
+            proposal.owner_set_seqno == multisig.owner_set_seqno,
+            StablecoinError::MultisigSignersChanged
+        );
+
+        let approvals = proposal.approvals.iter().filter(|approved| **approved).count();
+        require!(
+            approvals >= multisig.threshold as usize,
+            StablecoinError::NotEnoughApprovals
+        );
+
+        let multisig_key = multisig.key();
+        let instruction = Instruction {
+            program_id: crate::ID,
+            accounts: proposal
+                .accounts
+                .iter()
+                .map(|meta| AccountMeta {
+                    pubkey: meta.pubkey,
+                    is_signer: meta.is_signer || meta.pubkey == multisig_key,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: proposal.data.clone(),
+        };
+
+        let config_key = ctx.accounts.stablecoin_config.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"multisig",
+            config_key.as_ref(),
+            &[multisig.bump],
+        ]];
+        invoke_signed(&instruction, ctx.remaining_accounts, signer_seeds)?;
+
+        emit!(MultisigProposalExecuted {
+            mint: ctx.accounts.stablecoin_config.mint,
+            actor: ctx.accounts.signer.key(),
+            target: multisig_key,
+            nonce,
+            slot: Clock::get()?.slot,
+        });
+        Ok(())
+    }
+
+    /// File a request to mint `amount` into `destination` against an
+    /// off-chain wire; executed by a minter through `approve_mint_request`
+    pub fn request_mint(
+        ctx: Context<RequestMint>,
+        amount: u64,
+        wire_reference: [u8; 32],
+    ) -> Result<()> {
+        require!(amount > 0, StablecoinError::InvalidMintAmount);
+
+        let clock = Clock::get()?;
+        let stablecoin_config = &mut ctx.accounts.stablecoin_config;
+        let request_id = stablecoin_config.request_count;
+        stablecoin_config.request_count = request_id
+            .checked_add(1)
+            .ok_or(StablecoinError::MathOverflow)?;
+
+        let mint_request = &mut ctx.accounts.mint_request;
+        mint_request.stablecoin_config = stablecoin_config.key();
+        mint_request.id = request_id;
+        mint_request.requester = ctx.accounts.requester.key();
+        mint_request.destination = ctx.accounts.destination.key();
+        mint_request.amount = amount;
+        mint_request.wire_reference = wire_reference;
+        mint_request.status = RequestStatus::Pending;
+        mint_request.created_at = clock.unix_timestamp;
+        mint_request.resolved_at = 0;
+        mint_request.resolved_by = Pubkey::default();
+        mint_request.bump = ctx.bumps.mint_request;
+
+        emit!(MintRequested {
+            mint: stablecoin_config.mint,
+            actor: ctx.accounts.requester.key(),
+            target: ctx.accounts.destination.key(),
+            request_id,
+            amount,
+            wire_reference,
+            slot: clock.slot,
+        });
+        Ok(())
+    }
+
+    /// Approve a pending mint request and mint it in the same instruction
+    /// Subject to the same pause, allowlist, reserve and quota checks as `mint_to`
+    pub fn approve_mint_request(
+        ctx: Context<ApproveMintRequest>,
+        request_id: u64,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.mint_request.status == RequestStatus::Pending,
+            StablecoinError::RequestNotPending
+        );
+
+        let clock = Clock::get()?;
+        let amount = ctx.accounts.mint_request.amount;
+        mint_checked(
+            MintAccounts {
+                stablecoin_config: &mut ctx.accounts.stablecoin_config,
+                minter_info: &mut ctx.accounts.minter_info,
+                mint: &ctx.accounts.mint,
+                to: &ctx.accounts.destination,
+                allowlist_entry: &ctx.accounts.allowlist_entry,
+                reserve_attestation: &ctx.accounts.reserve_attestation,
+                token_program: &ctx.accounts.token_program,
+            },
+            amount,
+            clock.unix_timestamp,
+        )?;
+
+        let mint_key = ctx.accounts.mint.key();
+        ctx.accounts.mint_request.resolve(
+            RequestStatus::Approved,
+            ctx.accounts.authority.key(),
+            clock.unix_timestamp,
+        );
+
+        emit!(Minted {
+            mint: mint_key,
+            actor: ctx.accounts.authority.key(),
+            target: ctx.accounts.destination.key(),
+            amount,
+            slot: clock.slot,
+        });
+        emit!(MintRequestResolved {
+            mint: mint_key,
+            actor: ctx.accounts.authority.key(),
+            request_id,
+            status: RequestStatus::Approved,
+            slot: clock.slot,
+        });
+        Ok(())
+    }
+
+    /// Reject a pending mint request (minter only)
+    pub fn reject_mint_request(
+        ctx: Context<RejectMintRequest>,
+        request_id: u64,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.mint_request.status == RequestStatus::Pending,
+            StablecoinError::RequestNotPending
+        );
+
+        let clock = Clock::get()?;
+        ctx.accounts.mint_request.resolve(
+            RequestStatus::Rejected,
+            ctx.accounts.authority.key(),
+            clock.unix_timestamp,
+        );
+
+        emit!(MintRequestResolved {
+            mint: ctx.accounts.stablecoin_config.mint,
+            actor: ctx.accounts.authority.key(),
+            request_id,
+            status: RequestStatus::Rejected,
+            slot: clock.slot,
+        });
+        Ok(())
+    }
+
+    /// Withdraw a pending mint request (requester only)
+    pub fn cancel_mint_request(
+        ctx: Context<CancelMintRequest>,
+        request_id: u64,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.mint_request.status == RequestStatus::Pending,
+            StablecoinError::RequestNotPending
+        );
+
+        let clock = Clock::get()?;
+        ctx.accounts.mint_request.resolve(
+            RequestStatus::Cancelled,
+            ctx.accounts.requester.key(),
+            clock.unix_timestamp,
+        );
+
+        emit!(MintRequestResolved {
+            mint: ctx.accounts.stablecoin_config.mint,
+            actor: ctx.accounts.requester.key(),
+            request_id,
+            status: RequestStatus::Cancelled,
+            slot: clock.slot,
+        });
+        Ok(())
+    }
+
+    /// File a request to redeem `amount` from the holder's `source` account
+    /// against an off-chain wire; the tokens stay in `source` until
+    /// `approve_redemption_request` burns them. Blacklisted holders, and
+    /// holders missing from the allowlist in allowlist mode, cannot redeem
+    pub fn request_redemption(
+        ctx: Context<RequestRedemption>,
+        amount: u64,
+        wire_reference: [u8; 32],
+    ) -> Result<()> {
+        require!(
+            amount > 0 && amount <= ctx.accounts.source.amount,
+            StablecoinError::InvalidBurnAmount
+        );
+        assert_can_redeem(
+            &ctx.accounts.stablecoin_config,
+            &ctx.accounts.blacklist_entry,
+            &ctx.accounts.allowlist_entry,
+        )?;
+
+        let clock = Clock::get()?;
+        let stablecoin_config = &mut ctx.accounts.stablecoin_config;
+        let request_id = stablecoin_config.request_count;
+        stablecoin_config.request_count = request_id
+            .checked_add(1)
+            .ok_or(StablecoinError::MathOverflow)?;
+
+        let redemption_request = &mut ctx.accounts.redemption_request;
+        redemption_request.stablecoin_config = stablecoin_config.key();
+        redemption_request.id = request_id;
+        redemption_request.requester = ctx.accounts.requester.key();
+        redemption_request.source = ctx.accounts.source.key();
+        redemption_request.amount = amount;
+        redemption_request.wire_reference = wire_reference;
+        redemption_request.status = RequestStatus::Pending;
+        redemption_request.created_at = clock.unix_timestamp;
+        redemption_request.resolved_at = 0;
+        redemption_request.resolved_by = Pubkey::default();
+        redemption_request.bump = ctx.bumps.redemption_request;
+
+        emit!(RedemptionRequested {
+            mint: stablecoin_config.mint,
+            actor: ctx.accounts.requester.key(),
+            target: ctx.accounts.source.key(),
+            request_id,
+            amount,
+            wire_reference,
+            slot: clock.slot,
+        });
+        Ok(())
+    }
+
+    /// Approve a pending redemption and burn it in the same instruction
+    /// The burn goes through the PermanentDelegate, authorized by the
+    /// holder's signed request, so `source` must still belong to the
+    /// requester and the requester must still be allowed to redeem
+    pub fn approve_redemption_request(
+        ctx: Context<ApproveRedemptionRequest>,
+        request_id: u64,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.redemption_request.status == RequestStatus::Pending,
+            StablecoinError::RequestNotPending
+        );
+        require!(!ctx.accounts.stablecoin_config.paused, StablecoinError::Paused);
+        assert_can_redeem(
+            &ctx.accounts.stablecoin_config,
+            &ctx.accounts.blacklist_entry,
+            &ctx.accounts.allowlist_entry,
+        )?;
+
+        let amount = ctx.accounts.redemption_request.amount;
+        let mint_key = ctx.accounts.mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            b"stablecoin",
+            mint_key.as_ref(),
+            &[ctx.accounts.stablecoin_config.bump],
+        ]];
+
+        token_2022::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_2022::Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.source.to_account_info(),
+                    authority: ctx.accounts.stablecoin_config.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        let clock = Clock::get()?;
+        record_burn(&mut ctx.accounts.stablecoin_config, amount)?;
+        ctx.accounts.redemption_request.resolve(
+            RequestStatus::Approved,
+            ctx.accounts.authority.key(),
+            clock.unix_timestamp,
+        );
+
+        emit!(Burned {
+            mint: mint_key,
+            actor: ctx.accounts.authority.key(),
+            target: ctx.accounts.source.key(),
+            amount,
+            slot: clock.slot,
+        });
+        emit!(RedemptionRequestResolved {
+            mint: mint_key,
+            actor: ctx.accounts.authority.key(),
+            request_id,
+            status: RequestStatus::Approved,
+            slot: clock.slot,
+        });
+        Ok(())
+    }
+
+    /// Reject a pending redemption request (burner only)
+    pub fn reject_redemption_request(
+        ctx: Context<RejectRedemptionRequest>,
+        request_id: u64,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.redemption_request.status == RequestStatus::Pending,
+            StablecoinError::RequestNotPending
+        );
+
+        let clock = Clock::get()?;
+        ctx.accounts.redemption_request.resolve(
+            RequestStatus::Rejected,
+            ctx.accounts.authority.key(),
+            clock.unix_timestamp,
+        );
+
+        emit!(RedemptionRequestResolved {
+            mint: ctx.accounts.stablecoin_config.mint,
+            actor: ctx.accounts.authority.key(),
+            request_id,
+            status: RequestStatus::Rejected,
+            slot: clock.slot,
+        });
+        Ok(())
+    }
+
+    /// Withdraw a pending redemption request (requester only)
+    pub fn cancel_redemption_request(
+        ctx: Context<CancelRedemptionRequest>,
+        request_id: u64,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.redemption_request.status == RequestStatus::Pending,
+            StablecoinError::RequestNotPending
+        );
+
+        let clock = Clock::get()?;
+        ctx.accounts.redemption_request.resolve(
+            RequestStatus::Cancelled,
+            ctx.accounts.requester.key(),
+            clock.unix_timestamp,
+        );
+
+        emit!(RedemptionRequestResolved {
+            mint: ctx.accounts.stablecoin_config.mint,
+            actor: ctx.accounts.requester.key(),
+            request_id,
+            status: RequestStatus::Cancelled,
+            slot: clock.slot,
+        });
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Upper bound for `initialize(decimals)`; 6 is the usual fiat stablecoin value
+pub const MAX_DECIMALS: u8 = 9;
+
+// ============================================================================
+// Rent Helpers
+// ============================================================================
+
+/// Fund `account` so it stays rent-exempt once it grows to `new_len` bytes
+fn top_up_rent<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program_info: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(new_len);
+    let current = account.lamports();
+
+    if required > current {
+        system_program::transfer(
+            CpiContext::new(
+                system_program_info.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            required - current,
+        )?;
+    }
+    Ok(())
+}
+
+/// Manual equivalent of `init` for a PDA owned by this program
+/// Funding first also covers accounts that were pre-funded by a third party,
+/// which would make a plain `create_account` fail
+fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program_info: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    top_up_rent(account, payer, system_program_info, space)?;
+
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program_info.clone(),
+            system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program_info.clone(),
+            system_program::Assign {
+                account_to_assign: account.clone(),
+            },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )?;
+    Ok(())
+}
+
+// ============================================================================
+// Minter Quotas
+// ============================================================================
+
+/// Length of the rolling mint window
+pub const MINT_WINDOW_SECONDS: i64 = 24 * 60 * 60;
+
+/// The rolling window is tracked in hourly buckets
+pub const MINT_WINDOW_BUCKETS: usize = 24;
+
+const MINT_BUCKET_SECONDS: i64 = MINT_WINDOW_SECONDS / MINT_WINDOW_BUCKETS as i64;
+
+impl MinterInfo {
+    /// Whether the new quota lets the minter mint more than the current one;
+    /// a `daily_cap` of 0 is unlimited
+    pub fn is_quota_increase(&self, allowance: u64, daily_cap: u64) -> bool {
+        let cap_increased = match (self.daily_cap, daily_cap) {
+            (0, _) => false,
+            (_, 0) => true,
+            (current, new) => new > current,
+        };
+        allowance > self.allowance || cap_increased
+    }
+
+    /// Charge `amount` against the allowance and the rolling 24h cap
+    pub fn consume(&mut self, amount: u64, now: i64) -> Result<()> {
+        require!(amount <= self.allowance, StablecoinError::MinterAllowanceExceeded);
+
+        self.roll_window(now);
+        if self.daily_cap > 0 {
+            let minted_in_window = self
+                .window_buckets
+                .iter()
+                .try_fold(amount, |total, bucket| total.checked_add(*bucket))
+                .ok_or(StablecoinError::MathOverflow)?;
+            require!(
+                minted_in_window <= self.daily_cap,
+                StablecoinError::MinterDailyCapExceeded
+            );
+        }
+
+        let bucket = &mut self.window_buckets[(self.last_bucket as usize) % MINT_WINDOW_BUCKETS];
+        *bucket = bucket.checked_add(amount).ok_or(StablecoinError::MathOverflow)?;
+        self.allowance -= amount;
+        self.total_minted = self
+            .total_minted
+            .checked_add(amount)
+            .ok_or(StablecoinError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Zero out the hourly buckets that fell out of the 24h window
+    fn roll_window(&mut self, now: i64) {
+        let current_bucket = now / MINT_BUCKET_SECONDS;
+        let elapsed = current_bucket.saturating_sub(self.last_bucket);
+
+        if elapsed >= MINT_WINDOW_BUCKETS as i64 {
+            self.window_buckets = [0; MINT_WINDOW_BUCKETS];
+        } else {
+            for offset in 1..=elapsed {
+                let index = ((self.last_bucket + offset) as usize) % MINT_WINDOW_BUCKETS;
+                self.window_buckets[index] = 0;
+            }
+        }
+        self.last_bucket = current_bucket;
+    }
+}
+
+// ============================================================================
+// Default Account State
+// ============================================================================
+
+/// Set the DefaultAccountState extension, signed by the config PDA as freeze
+/// authority
+fn update_default_account_state<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    stablecoin_config: &Account<'info, StablecoinConfig>,
+    frozen: bool,
+) -> Result<()> {
+    let mint_key = mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"stablecoin",
+        mint_key.as_ref(),
+        &[stablecoin_config.bump],
+    ]];
+
+    let state = if frozen {
+        AccountState::Frozen
+    } else {
+        AccountState::Initialized
+    };
+    default_account_state_update(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            DefaultAccountStateUpdate {
+                token_program_id: token_program.clone(),
+                mint: mint.clone(),
+                freeze_authority: stablecoin_config.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        &state,
+    )
+}
+
+// ============================================================================
+// Confidential Transfers
+// ============================================================================
+
+/// Current auditor key, which `UpdateMint` would otherwise overwrite
+fn confidential_transfer_auditor(mint: &AccountInfo) -> Result<Option<ElGamalPubkey>> {
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?;
+    let extension = mint
+        .get_extension::<ConfidentialTransferMint>()
+        .map_err(|_| error!(StablecoinError::ConfidentialTransfersDisabled))?;
+
+    Ok(extension.auditor_elgamal_pubkey.into())
+}
+
+/// Set `auto_approve_new_accounts` on the ConfidentialTransferMint extension,
+/// signed by the config PDA as its authority
+fn update_confidential_auto_approve<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    stablecoin_config: &Account<'info, StablecoinConfig>,
+    auto_approve_new_accounts: bool,
+) -> Result<()> {
+    let auditor_elgamal_pubkey = confidential_transfer_auditor(mint)?;
+
+    let mint_key = mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"stablecoin",
+        mint_key.as_ref(),
+        &[stablecoin_config.bump],
+    ]];
+
+    let ix = confidential_transfer::instruction::update_mint(
+        token_program.key,
+        &mint_key,
+        &stablecoin_config.key(),
+        &[],
+        auto_approve_new_accounts,
+        auditor_elgamal_pubkey,
+    )?;
+    invoke_signed(
+        &ix,
+        &[
+            mint.clone(),
+            stablecoin_config.to_account_info(),
+            token_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+/// Whether the account is configured for confidential transfers and may hold
+/// an encrypted balance, which the permanent delegate cannot transfer
+fn has_confidential_balance(token_account: &AccountInfo) -> Result<bool> {
+    let data = token_account.try_borrow_data()?;
+    let account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+
+    Ok(account.get_extension::<ConfidentialTransferAccount>().is_ok())
+}
+
+// ============================================================================
+// Proof of Reserves
+// ============================================================================
+
+/// Post-mint supply must stay within the latest attestation, which must be
+/// no older than `reserve_staleness_seconds`; minting without one fails
+fn assert_reserves_cover(
+    stablecoin_config: &StablecoinConfig,
+    reserve_attestation: &AccountInfo,
+    supply: u64,
+    amount: u64,
+    now: i64,
+) -> Result<()> {
+    require!(
+        !reserve_attestation.data_is_empty() && reserve_attestation.owner == &crate::ID,
+        StablecoinError::InsufficientReserves
+    );
+
+    let data = reserve_attestation.try_borrow_data()?;
+    let attestation = ReserveAttestation::try_deserialize(&mut &data[..])?;
+    require!(
+        now.saturating_sub(attestation.attested_at) <= stablecoin_config.reserve_staleness_seconds,
+        StablecoinError::StaleReserveAttestation
+    );
+
+    let new_supply = supply.checked_add(amount).ok_or(StablecoinError::MathOverflow)?;
+    require!(
+        new_supply <= attestation.reserves,
+        StablecoinError::InsufficientReserves
+    );
+    Ok(())
+}
+
+fn assert_valid_reserve_staleness(reserve_staleness_seconds: i64) -> Result<()> {
+    require!(reserve_staleness_seconds > 0, StablecoinError::InvalidReserveStaleness);
+    Ok(())
+}
+
+// ============================================================================
+// Transfer Fees
+// ============================================================================
+
+/// 100% in basis points
+pub const MAX_FEE_BASIS_POINTS: u16 = 10_000;
+
+fn assert_valid_transfer_fee(basis_points: u16) -> Result<()> {
+    require!(
+        basis_points <= MAX_FEE_BASIS_POINTS,
+        StablecoinError::InvalidTransferFee
+    );
+    Ok(())
+}
+
+// ============================================================================
+// Governance
+// ============================================================================
+
+/// Upper bound for `timelock_seconds`, so a typo cannot lock governance out
+pub const MAX_TIMELOCK_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+/// How long after `eta` a queued action stays executable; stale proposals
+/// have to be queued again
+pub const PROPOSAL_GRACE_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// discriminator + config + role + holder + granted_by + timestamp + bump
+pub const ROLE_GRANT_SPACE: usize = 8 + 32 + 1 + 32 + 32 + 8 + 1;
+
+/// Sensitive operations must be queued through `propose_action` while the
+/// timelock is on
+fn assert_no_timelock(stablecoin_config: &StablecoinConfig) -> Result<()> {
+    require!(
+        stablecoin_config.timelock_seconds == 0,
+        StablecoinError::TimelockRequired
+    );
+    Ok(())
+}
+
+fn assert_valid_timelock(timelock_seconds: i64) -> Result<()> {
+    require!(
+        (0..=MAX_TIMELOCK_SECONDS).contains(&timelock_seconds),
+        StablecoinError::InvalidTimelock
+    );
+    Ok(())
+}
+
+// ============================================================================
+// Admin Multisig
+// ============================================================================
+
+/// Upper bound on `AdminMultisig::signers`
+pub const MAX_MULTISIG_SIGNERS: usize = 10;
+
+/// Upper bound on the accounts of a proposed instruction
+pub const MAX_PROPOSAL_ACCOUNTS: usize = 16;
+
+/// Upper bound on the data of a proposed instruction
+pub const MAX_PROPOSAL_DATA_LEN: usize = 512;
+
+fn assert_valid_multisig(signers: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !signers.is_empty() && signers.len() <= MAX_MULTISIG_SIGNERS,
+        StablecoinError::InvalidMultisig
+    );
+    require!(
+        threshold > 0 && threshold as usize <= signers.len(),
+        StablecoinError::InvalidMultisig
+    );
+    for (index, signer) in signers.iter().enumerate() {
+        require!(
+            !signers[..index].contains(signer),
+            StablecoinError::InvalidMultisig
+        );
+    }
+    Ok(())
+}
+
+impl AdminMultisig {
+    /// Position of `signer` in the signer set, which is also its slot in
+    /// `MultisigProposal::approvals`
+    pub fn signer_index(&self, signer: &Pubkey) -> Result<usize> {
+        self.signers
+            .iter()
+            .position(|key| key == signer)
+            .ok_or_else(|| error!(StablecoinError::NotMultisigSigner))
+    }
+}
+
+// ============================================================================
+// Supply Accounting
+// ============================================================================
+
+fn record_mint(stablecoin_config: &mut StablecoinConfig, amount: u64) -> Result<()> {
+    stablecoin_config.total_minted = stablecoin_config
+        .total_minted
+        .checked_add(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+    stablecoin_config.circulating_supply = stablecoin_config
+        .circulating_supply
+        .checked_add(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+    Ok(())
+}
+
+fn record_burn(stablecoin_config: &mut StablecoinConfig, amount: u64) -> Result<()> {
+    stablecoin_config.total_burned = stablecoin_config
+        .total_burned
+        .checked_add(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+    stablecoin_config.circulating_supply = stablecoin_config
+        .circulating_supply
+        .checked_sub(amount)
+        .ok_or(StablecoinError::MathOverflow)?;
+    Ok(())
+}
+
+/// Accounts every mint path goes through `mint_checked` with
+struct MintAccounts<'a, 'info> {
+    stablecoin_config: &'a mut Account<'info, StablecoinConfig>,
+    minter_info: &'a mut Account<'info, MinterInfo>,
+    mint: &'a InterfaceAccount<'info, Mint>,
+    to: &'a InterfaceAccount<'info, TokenAccount>,
+    /// Allowlist entry of `to.owner`
+    allowlist_entry: &'a AccountInfo<'info>,
+    reserve_attestation: &'a AccountInfo<'info>,
+    token_program: &'a Program<'info, Token2022>,
+}
+
+/// Pause, allowlist, reserve and quota checks shared by `mint_to` and
+/// `approve_mint_request`, followed by the mint itself
+fn mint_checked(accounts: MintAccounts, amount: u64, now: i64) -> Result<()> {
+    let MintAccounts {
+        stablecoin_config,
+        minter_info,
+        mint,
+        to,
+        allowlist_entry,
+        reserve_attestation,
+        token_program,
+    } = accounts;
+
+    require!(amount > 0, StablecoinError::InvalidMintAmount);
+    require!(!stablecoin_config.paused, StablecoinError::Paused);
+    if stablecoin_config.compliance_mode == ComplianceMode::Allowlist {
+        require!(
+            is_allowlisted(allowlist_entry)?,
+            StablecoinError::AddressNotAllowlisted
+        );
+    }
+
+    assert_reserves_cover(stablecoin_config, reserve_attestation, mint.supply, amount, now)?;
+    minter_info.consume(amount, now)?;
+
+    let mint_key = mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"stablecoin",
+        mint_key.as_ref(),
+        &[stablecoin_config.bump],
+    ]];
+
+    token_2022::mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token_2022::MintTo {
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: stablecoin_config.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    record_mint(stablecoin_config, amount)
+}
+
+// ============================================================================
+// Transfer Hook Helpers
+// ============================================================================
+
+/// Reject hook invocations that do not come from an in-flight Token-2022 transfer
+fn assert_is_transferring(source_token: &AccountInfo) -> Result<()> {
+    let data = source_token.try_borrow_data()?;
+    let account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+    let extension = account.get_extension::<TransferHookAccount>()?;
+
+    require!(bool::from(extension.transferring), StablecoinError::TransferHookError);
+    Ok(())
+}
+
+/// A missing entry means the owner was never blacklisted
+fn is_blacklisted(entry: &AccountInfo) -> Result<bool> {
+    if entry.data_is_empty() || entry.owner != &crate::ID {
+        return Ok(false);
+    }
+
+    let data = entry.try_borrow_data()?;
+    let entry = BlacklistEntry::try_deserialize(&mut &data[..])?;
+    Ok(entry.is_blacklisted)
+}
+
+/// A missing entry means the owner was never allowlisted
+fn is_allowlisted(entry: &AccountInfo) -> Result<bool> {
+    if entry.data_is_empty() || entry.owner != &crate::ID {
+        return Ok(false);
+    }
+
+    let data = entry.try_borrow_data()?;
+    let entry = AllowlistEntry::try_deserialize(&mut &data[..])?;
+    Ok(entry.is_allowed)
+}
+
+/// Blacklisted owners can never redeem; in allowlist mode the owner also has
+/// to be allowlisted
+fn assert_can_redeem(
+    stablecoin_config: &StablecoinConfig,
+    blacklist_entry: &AccountInfo,
+    allowlist_entry: &AccountInfo,
+) -> Result<()> {
+    require!(
+        !is_blacklisted(blacklist_entry)?,
+        StablecoinError::AddressBlacklisted
+    );
+    if stablecoin_config.compliance_mode == ComplianceMode::Allowlist {
+        require!(
+            is_allowlisted(allowlist_entry)?,
+            StablecoinError::AddressNotAllowlisted
+        );
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Batch Helpers
+// ============================================================================
+
+/// Upper bound on targets per batch instruction, sized so a batch fits in
+/// one transaction when the accounts come from a lookup table
+pub const MAX_BATCH_SIZE: usize = 20;
+
+/// discriminator + address + bool + timestamp + reason_code + case_reference
+pub const BLACKLIST_ENTRY_SPACE: usize = 8 + 32 + 1 + 8 + (1 + 2) + (1 + 32);
+
+/// Every target needs exactly one matching remaining account
+fn assert_batch(targets: &[Pubkey], remaining_accounts: &[AccountInfo]) -> Result<()> {
+    require!(
+        !targets.is_empty() && targets.len() <= MAX_BATCH_SIZE,
+        StablecoinError::InvalidBatchSize
+    );
+    require!(
+        targets.len() == remaining_accounts.len(),
+        StablecoinError::BatchAccountsMismatch
+    );
+    Ok(())
+}
+
+/// Returns the PDA bump so the caller can sign for the entry
+fn assert_blacklist_entry_address(
+    entry_info: &AccountInfo,
+    stablecoin_config: &Pubkey,
+    address: &Pubkey,
+) -> Result<u8> {
+    let (expected, bump) = Pubkey::find_program_address(
+        &[b"blacklist", stablecoin_config.as_ref(), address.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(entry_info.key(), expected, StablecoinError::InvalidBlacklistEntry);
+    Ok(bump)
+}
+
+/// Batch equivalent of `init_if_needed` on `ModifyBlacklist::blacklist_entry`
+fn load_or_create_blacklist_entry<'info>(
+    entry_info: &AccountInfo<'info>,
+    stablecoin_config: &Pubkey,
+    address: &Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program_info: &AccountInfo<'info>,
+) -> Result<BlacklistEntry> {
+    let bump = assert_blacklist_entry_address(entry_info, stablecoin_config, address)?;
+
+    if !entry_info.data_is_empty() {
+        require!(entry_info.owner == &crate::ID, StablecoinError::InvalidBlacklistEntry);
+        return BlacklistEntry::try_deserialize(&mut &entry_info.try_borrow_data()?[..]);
+    }
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"blacklist",
+        stablecoin_config.as_ref(),
+        address.as_ref(),
+        &[bump],
+    ]];
+    create_pda_account(
+        entry_info,
+        payer,
+        system_program_info,
+        BLACKLIST_ENTRY_SPACE,
+        signer_seeds,
+    )?;
+
+    Ok(BlacklistEntry {
+        address: *address,
+        is_blacklisted: false,
+        timestamp: 0,
+        reason_code: None,
+        case_reference: None,
+    })
+}
+
+// ============================================================================
+// Contexts (Account Validation)
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + (4 + 32) + (4 + 16) + (4 + 256) // discriminator + strings
+            + 1 + 32 // decimals + mint
+            + 32 + (1 + 32) + 1 // master_admin + pending_master_admin + bump
+            + 8 + 8 + 8 // total_minted + total_burned + circulating_supply
+            + 1 // paused
+            + 1 // compliance_mode
+            + 32 // rent_collector
+            + 8 + 8 // timelock_seconds + proposal_count
+            + (1 + 32) // treasury
+            + 8 // reserve_staleness_seconds
+            + 8, // request_count
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + std::mem::size_of::<ComplianceLog>(),
+        seeds = [b"compliance_log", stablecoin_config.key().as_ref()],
+        bump
+    )]
+    pub compliance_log: AccountLoader<'info, ComplianceLog>,
+
+    /// Created and initialized with its extensions in the handler
+    #[account(mut)]
+    pub mint: Signer<'info>,
+
+    /// CHECK: `[b"rate_history", config]`, only required with `interest_rate`;
+    /// address checked and the account created by the handler
+    #[account(mut)]
+    pub rate_history: Option<UncheckedAccount<'info>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    /// CHECK: ExtraAccountMetaList account, written in the handler
+    #[account(
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(
+            InitializeExtraAccountMetaList::extra_account_metas()?.len()
+        )?,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeExtraAccountMetaList<'info> {
+    /// Accounts appended by Token-2022 to every `Execute` call, after the
+    /// fixed source / mint / destination / owner / meta-list accounts
+    pub fn extra_account_metas() -> Result<Vec<ExtraAccountMeta>> {
+        Ok(vec![
+            // index 5: stablecoin config, for the pause flag and blacklist scoping
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal { bytes: b"stablecoin".to_vec() },
+                    Seed::AccountKey { index: 1 },
+                ],
+                false, // is_signer
+                false, // is_writable
+            )?,
+            // index 6: blacklist entry of the source token account owner
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal { bytes: b"blacklist".to_vec() },
+                    Seed::AccountKey { index: 5 },
+                    Seed::AccountData { account_index: 0, data_index: 32, length: 32 },
+                ],
+                false, // is_signer
+                false, // is_writable
+            )?,
+            // index 7: blacklist entry of the destination token account owner
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal { bytes: b"blacklist".to_vec() },
+                    Seed::AccountKey { index: 5 },
+                    Seed::AccountData { account_index: 2, data_index: 32, length: 32 },
+                ],
+                false, // is_signer
+                false, // is_writable
+            )?,
+            // index 8: allowlist entry of the source token account owner
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal { bytes: b"allowlist".to_vec() },
+                    Seed::AccountKey { index: 5 },
+                    Seed::AccountData { account_index: 0, data_index: 32, length: 32 },
+                ],
+                false, // is_signer
+                false, // is_writable
+            )?,
+            // index 9: allowlist entry of the destination token account owner
+            ExtraAccountMeta::new_with_seeds(
+                &[
+                    Seed::Literal { bytes: b"allowlist".to_vec() },
+                    Seed::AccountKey { index: 5 },
+                    Seed::AccountData { account_index: 2, data_index: 32, length: 32 },
+                ],
+                false, // is_signer
+                false, // is_writable
+            )?,
+        ])
+    }
+}
+
+/// Account order is fixed by the SPL transfer-hook interface
+#[derive(Accounts)]
+pub struct ExecuteTransferHook<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: source owner or delegate, validated by Token-2022
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: ExtraAccountMetaList account
+    #[account(
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    /// CHECK: uninitialized when the owner was never blacklisted
+    #[account(
+        seeds = [
+            b"blacklist",
+            stablecoin_config.key().as_ref(),
+            source_token.owner.as_ref(),
+        ],
+        bump
+    )]
+    pub source_blacklist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: uninitialized when the owner was never blacklisted
+    #[account(
+        seeds = [
+            b"blacklist",
+            stablecoin_config.key().as_ref(),
+            destination_token.owner.as_ref(),
+        ],
+        bump
+    )]
+    pub destination_blacklist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: uninitialized when the owner was never allowlisted
+    #[account(
+        seeds = [
+            b"allowlist",
+            stablecoin_config.key().as_ref(),
+            source_token.owner.as_ref(),
+        ],
+        bump
+    )]
+    pub source_allowlist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: uninitialized when the owner was never allowlisted
+    #[account(
+        seeds = [
+            b"allowlist",
+            stablecoin_config.key().as_ref(),
+            destination_token.owner.as_ref(),
+        ],
+        bump
+    )]
+    pub destination_allowlist_entry: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MintTo<'info> {
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub to: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: recipient owner's allowlist entry, only read in allowlist mode
+    #[account(
+        seeds = [
+            b"allowlist",
+            stablecoin_config.key().as_ref(),
+            to.owner.as_ref(),
+        ],
+        bump
+    )]
+    pub allowlist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: `ReserveAttestation` PDA, deserialized by `assert_reserves_cover`
+    #[account(
+        seeds = [b"reserves", stablecoin_config.key().as_ref()],
+        bump
+    )]
+    pub reserve_attestation: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"minter",
+            stablecoin_config.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = minter_info.bump
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Minter as u8],
+            authority.key().as_ref(),
+        ],
+        bump = minter_role.bump
+    )]
+    pub minter_role: Account<'info, RoleGrant>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct BurnTokens<'info> {
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct BurnFrom<'info> {
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Burner as u8],
+            authority.key().as_ref(),
+        ],
+        bump = burner_role.bump
+    )]
+    pub burner_role: Account<'info, RoleGrant>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct FreezeAccount<'info> {
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"compliance_log", stablecoin_config.key().as_ref()],
+        bump
+    )]
+    pub compliance_log: AccountLoader<'info, ComplianceLog>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Blacklister as u8],
+            authority.key().as_ref(),
+        ],
+        bump = blacklister_role.bump
+    )]
+    pub blacklister_role: Account<'info, RoleGrant>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct ThawAccount<'info> {
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"compliance_log", stablecoin_config.key().as_ref()],
+        bump
+    )]
+    pub compliance_log: AccountLoader<'info, ComplianceLog>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Blacklister as u8],
+            authority.key().as_ref(),
+        ],
+        bump = blacklister_role.bump
+    )]
+    pub blacklister_role: Account<'info, RoleGrant>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct ModifyBlacklist<'info> {
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = BLACKLIST_ENTRY_SPACE,
+        seeds = [b"blacklist", stablecoin_config.key().as_ref(), address.as_ref()],
+        bump
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"compliance_log", stablecoin_config.key().as_ref()],
+        bump
+    )]
+    pub compliance_log: AccountLoader<'info, ComplianceLog>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Blacklister as u8],
+            authority.key().as_ref(),
+        ],
+        bump = blacklister_role.bump
+    )]
+    pub blacklister_role: Account<'info, RoleGrant>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct RemoveFromBlacklist<'info> {
+    #[account(
+        mut,
+        seeds = [b"blacklist", stablecoin_config.key().as_ref(), address.as_ref()],
+        bump
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"compliance_log", stablecoin_config.key().as_ref()],
+        bump
+    )]
+    pub compliance_log: AccountLoader<'info, ComplianceLog>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Blacklister as u8],
+            authority.key().as_ref(),
+        ],
+        bump = blacklister_role.bump
+    )]
+    pub blacklister_role: Account<'info, RoleGrant>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct CloseBlacklistEntry<'info> {
+    #[account(
+        mut,
+        close = rent_collector,
+        seeds = [b"blacklist", stablecoin_config.key().as_ref(), address.as_ref()],
+        bump
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: only receives lamports; pinned to the configured collector
+    #[account(mut, address = stablecoin_config.rent_collector)]
+    pub rent_collector: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Blacklister as u8],
+            authority.key().as_ref(),
+        ],
+        bump = blacklister_role.bump
+    )]
+    pub blacklister_role: Account<'info, RoleGrant>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Blacklist PDAs are passed as remaining accounts
+#[derive(Accounts)]
+pub struct BatchModifyBlacklist<'info> {
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"compliance_log", stablecoin_config.key().as_ref()],
+        bump
+    )]
+    pub compliance_log: AccountLoader<'info, ComplianceLog>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Blacklister as u8],
+            authority.key().as_ref(),
+        ],
+        bump = blacklister_role.bump
+    )]
+    pub blacklister_role: Account<'info, RoleGrant>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Token accounts are passed as remaining accounts
+#[derive(Accounts)]
+pub struct BatchFreeze<'info> {
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"compliance_log", stablecoin_config.key().as_ref()],
+        bump
+    )]
+    pub compliance_log: AccountLoader<'info, ComplianceLog>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Blacklister as u8],
+            authority.key().as_ref(),
+        ],
+        bump = blacklister_role.bump
+    )]
+    pub blacklister_role: Account<'info, RoleGrant>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct ModifyAllowlist<'info> {
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + 32 + 1 + 8, // discriminator + address + bool + timestamp
+        seeds = [b"allowlist", stablecoin_config.key().as_ref(), address.as_ref()],
+        bump
+    )]
+    pub allowlist_entry: Account<'info, AllowlistEntry>,
+
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"compliance_log", stablecoin_config.key().as_ref()],
+        bump
+    )]
+    pub compliance_log: AccountLoader<'info, ComplianceLog>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Allowlister as u8],
+            authority.key().as_ref(),
+        ],
+        bump = allowlister_role.bump
+    )]
+    pub allowlister_role: Account<'info, RoleGrant>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveHolder<'info> {
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + 32 + 32 + 1 + 2 + 8 + 32 + 8 + 1, // discriminator + config + owner + kyc_tier + jurisdiction + expiry + approved_by + timestamp + bump
+        seeds = [b"holder", stablecoin_config.key().as_ref(), token_account.owner.as_ref()],
+        bump
+    )]
+    pub holder_record: Account<'info, HolderRecord>,
+
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: token account owner's blacklist entry, read in the handler
+    #[account(
+        seeds = [b"blacklist", stablecoin_config.key().as_ref(), token_account.owner.as_ref()],
+        bump
+    )]
+    pub blacklist_entry: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"compliance_log", stablecoin_config.key().as_ref()],
+        bump
+    )]
+    pub compliance_log: AccountLoader<'info, ComplianceLog>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Allowlister as u8],
+            authority.key().as_ref(),
+        ],
+        bump = allowlister_role.bump
+    )]
+    pub allowlister_role: Account<'info, RoleGrant>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureConfidentialTransfers<'info> {
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveConfidentialAccount<'info> {
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"compliance_log", stablecoin_config.key().as_ref()],
+        bump
+    )]
+    pub compliance_log: AccountLoader<'info, ComplianceLog>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Allowlister as u8],
+            authority.key().as_ref(),
+        ],
+        bump = allowlister_role.bump
+    )]
+    pub allowlister_role: Account<'info, RoleGrant>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateTransferFee<'info> {
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::FeeAdmin as u8],
+            authority.key().as_ref(),
+        ],
+        bump = fee_admin_role.bump
+    )]
+    pub fee_admin_role: Account<'info, RoleGrant>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct SetTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Token accounts holding withheld fees are passed as remaining accounts
+#[derive(Accounts)]
+pub struct SweepFees<'info> {
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = stablecoin_config.treasury == Some(treasury.key()) @ StablecoinError::InvalidTreasury,
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::FeeAdmin as u8],
+            authority.key().as_ref(),
+        ],
+        bump = fee_admin_role.bump
+    )]
+    pub fee_admin_role: Account<'info, RoleGrant>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct AttestReserves<'info> {
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + 32 + 8 + 8 + 32 + 32 + 8 + 1, // discriminator + config + reserves + attested_at + report_hash + attestor + slot + bump
+        seeds = [b"reserves", stablecoin_config.key().as_ref()],
+        bump
+    )]
+    pub reserve_attestation: Account<'info, ReserveAttestation>,
+
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Attestor as u8],
+            authority.key().as_ref(),
+        ],
+        bump = attestor_role.bump
+    )]
+    pub attestor_role: Account<'info, RoleGrant>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRate<'info> {
+    #[account(
+        mut,
+        seeds = [b"rate_history", stablecoin_config.key().as_ref()],
+        bump = rate_history.bump
+    )]
+    pub rate_history: Account<'info, RateHistory>,
+
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::RateAuthority as u8],
+            authority.key().as_ref(),
+        ],
+        bump = rate_authority_role.bump
+    )]
+    pub rate_authority_role: Account<'info, RoleGrant>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct SetDefaultAccountState<'info> {
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct SeizeTokens<'info> {
+    #[account(
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub from: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        seeds = [
+            b"blacklist",
+            stablecoin_config.key().as_ref(),
+            from.owner.as_ref(),
+        ],
+        bump
+    )]
+    pub blacklist_entry: Account<'info, BlacklistEntry>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub treasury: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"compliance_log", stablecoin_config.key().as_ref()],
+        bump
+    )]
+    pub compliance_log: AccountLoader<'info, ComplianceLog>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Seizer as u8],
+            authority.key().as_ref(),
+        ],
+        bump = seizer_role.bump
+    )]
+    pub seizer_role: Account<'info, RoleGrant>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+#[instruction(minter: Pubkey)]
+pub struct ConfigureMinter<'info> {
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + 32 + 32 + 8 + 8 + 8 + 8 * MINT_WINDOW_BUCKETS + 8 + 1, // discriminator + config + minter + allowance + cap + last bucket + buckets + total + bump
+        seeds = [
+            b"minter",
+            stablecoin_config.key().as_ref(),
+            minter.as_ref(),
+        ],
+        bump
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::MasterMinter as u8],
+            authority.key().as_ref(),
+        ],
+        bump = master_minter_role.bump
+    )]
+    pub master_minter_role: Account<'info, RoleGrant>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(minter: Pubkey)]
+pub struct RemoveMinter<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"minter",
+            stablecoin_config.key().as_ref(),
+            minter.as_ref(),
+        ],
+        bump = minter_info.bump
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::MasterMinter as u8],
+            authority.key().as_ref(),
+        ],
+        bump = master_minter_role.bump
+    )]
+    pub master_minter_role: Account<'info, RoleGrant>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMetadata<'info> {
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::MetadataAdmin as u8],
+            authority.key().as_ref(),
+        ],
+        bump = metadata_admin_role.bump
+    )]
+    pub metadata_admin_role: Account<'info, RoleGrant>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(mut)]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Pauser as u8],
+            authority.key().as_ref(),
+        ],
+        bump = pauser_role.bump
+    )]
+    pub pauser_role: Account<'info, RoleGrant>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(role: Role, holder: Pubkey)]
+pub struct GrantRole<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = ROLE_GRANT_SPACE,
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[role as u8],
+            holder.as_ref(),
+        ],
+        bump
+    )]
+    pub role_grant: Account<'info, RoleGrant>,
+
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(role: Role, holder: Pubkey)]
+pub struct RevokeRole<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[role as u8],
+            holder.as_ref(),
+        ],
+        bump = role_grant.bump
+    )]
+    pub role_grant: Account<'info, RoleGrant>,
+
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
 
 #[derive(Accounts)]
-pub struct ModifyBlacklist<'info> {
+pub struct UpdateAuthority<'info> {
+    #[account(mut)]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAction<'info> {
     #[account(
-        init_if_needed,
+        init,
         payer = payer,
-        space = 8 + 32 + 1 + 8, // discriminator + address + bool + timestamp
-        seeds = [b"blacklist", address.as_ref()],
+        space = 8 + 32 + 8 + (1 + 32 + 8 + 8) + 32 + 32 + 8 + 8 + 1, // discriminator + config + id + largest action + master_admin + payer + created_at + eta + bump
+        seeds = [
+            b"proposal",
+            stablecoin_config.key().as_ref(),
+            &stablecoin_config.proposal_count.to_le_bytes(),
+        ],
         bump
     )]
-    pub blacklist_entry: Account<'info, BlacklistEntry>,
+    pub proposed_action: Account<'info, ProposedAction>,
+
+    #[account(mut)]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(action_id: u64)]
+pub struct ExecuteAction<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            b"proposal",
+            stablecoin_config.key().as_ref(),
+            &action_id.to_le_bytes(),
+        ],
+        bump = proposed_action.bump
+    )]
+    pub proposed_action: Account<'info, ProposedAction>,
 
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
     pub stablecoin_config: Account<'info, StablecoinConfig>,
 
     #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Only required for `GovernanceAction::ConfigureMinter`
+    #[account(mut)]
+    pub minter_info: Option<Account<'info, MinterInfo>>,
+
+    /// CHECK: only required for `GovernanceAction::GrantRole`; address checked
+    /// against the role PDA seeds and created by the handler
+    #[account(mut)]
+    pub role_grant: Option<UncheckedAccount<'info>>,
+
+    /// Only required for `GovernanceAction::SetTreasury`
+    #[account(
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub treasury: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: refunded the proposal rent; pinned to the original payer
+    #[account(mut, address = proposed_action.payer)]
+    pub payer: UncheckedAccount<'info>,
+
+    /// Pays for a `GrantRole` PDA
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(action_id: u64)]
+pub struct CancelAction<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            b"proposal",
+            stablecoin_config.key().as_ref(),
+            &action_id.to_le_bytes(),
+        ],
+        bump = proposed_action.bump
+    )]
+    pub proposed_action: Account<'info, ProposedAction>,
+
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    /// CHECK: refunded the proposal rent; pinned to the original payer
+    #[account(mut, address = proposed_action.payer)]
+    pub payer: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Guardian as u8],
+            authority.key().as_ref(),
+        ],
+        bump = guardian_role.bump
+    )]
+    pub guardian_role: Account<'info, RoleGrant>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateMultisig<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 32 + (4 + 32 * MAX_MULTISIG_SIGNERS) + 1 + 8 + 4 + 1, // discriminator + config + signers + threshold + nonce + owner_set_seqno + bump
+        seeds = [b"multisig", stablecoin_config.key().as_ref()],
+        bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
     pub authority: Signer<'info>,
 
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    pub system_program: Program<'info, System>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetMultisigSigners<'info> {
+    /// Signs through `execute_proposal`
+    #[account(
+        mut,
+        signer,
+        seeds = [b"multisig", stablecoin_config.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(accounts: Vec<MultisigAccountMeta>, data: Vec<u8>)]
+pub struct CreateProposal<'info> {
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + 32 + 8 + 4 + 32 // discriminator + multisig + nonce + owner_set_seqno + proposer
+            + (4 + 34 * MAX_PROPOSAL_ACCOUNTS) // accounts
+            + (4 + MAX_PROPOSAL_DATA_LEN) // data
+            + (4 + MAX_MULTISIG_SIGNERS) // approvals
+            + 1, // bump
+        seeds = [
+            b"multisig_proposal",
+            multisig.key().as_ref(),
+            &multisig.nonce.to_le_bytes(),
+        ],
+        bump,
+        constraint = accounts.len() <= MAX_PROPOSAL_ACCOUNTS
+            && data.len() <= MAX_PROPOSAL_DATA_LEN @ StablecoinError::ProposalTooLarge
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+
+    #[account(
+        mut,
+        seeds = [b"multisig", stablecoin_config.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ApproveProposal<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"multisig_proposal",
+            multisig.key().as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+
+    #[account(
+        seeds = [b"multisig", stablecoin_config.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [
+            b"multisig_proposal",
+            multisig.key().as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, MultisigProposal>,
+
+    #[account(
+        seeds = [b"multisig", stablecoin_config.key().as_ref()],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    /// CHECK: refunded the proposal rent; pinned to the original proposer
+    #[account(mut, address = proposal.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RequestMint<'info> {
+    #[account(
+        init,
+        payer = requester,
+        space = 8 + 32 + 8 + 32 + 32 + 8 + 32 + 1 + 8 + 8 + 32 + 1, // discriminator + config + id + requester + destination + amount + wire_reference + status + created_at + resolved_at + resolved_by + bump
+        seeds = [
+            b"mint_request",
+            stablecoin_config.key().as_ref(),
+            &stablecoin_config.request_count.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub mint_request: Account<'info, MintRequest>,
+
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct ApproveMintRequest<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"mint_request",
+            stablecoin_config.key().as_ref(),
+            &request_id.to_le_bytes(),
+        ],
+        bump = mint_request.bump
+    )]
+    pub mint_request: Account<'info, MintRequest>,
+
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        address = mint_request.destination,
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: recipient owner's allowlist entry, only read in allowlist mode
+    #[account(
+        seeds = [
+            b"allowlist",
+            stablecoin_config.key().as_ref(),
+            destination.owner.as_ref(),
+        ],
+        bump
+    )]
+    pub allowlist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: `ReserveAttestation` PDA, deserialized by `assert_reserves_cover`
+    #[account(
+        seeds = [b"reserves", stablecoin_config.key().as_ref()],
+        bump
+    )]
+    pub reserve_attestation: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"minter",
+            stablecoin_config.key().as_ref(),
+            authority.key().as_ref(),
+        ],
+        bump = minter_info.bump
+    )]
+    pub minter_info: Account<'info, MinterInfo>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Minter as u8],
+            authority.key().as_ref(),
+        ],
+        bump = minter_role.bump
+    )]
+    pub minter_role: Account<'info, RoleGrant>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct RejectMintRequest<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"mint_request",
+            stablecoin_config.key().as_ref(),
+            &request_id.to_le_bytes(),
+        ],
+        bump = mint_request.bump
+    )]
+    pub mint_request: Account<'info, MintRequest>,
+
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Minter as u8],
+            authority.key().as_ref(),
+        ],
+        bump = minter_role.bump
+    )]
+    pub minter_role: Account<'info, RoleGrant>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct CancelMintRequest<'info> {
+    #[account(
+        mut,
+        has_one = requester,
+        seeds = [
+            b"mint_request",
+            stablecoin_config.key().as_ref(),
+            &request_id.to_le_bytes(),
+        ],
+        bump = mint_request.bump
+    )]
+    pub mint_request: Account<'info, MintRequest>,
+
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    pub requester: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RequestRedemption<'info> {
+    #[account(
+        init,
+        payer = requester,
+        space = 8 + 32 + 8 + 32 + 32 + 8 + 32 + 1 + 8 + 8 + 32 + 1, // discriminator + config + id + requester + source + amount + wire_reference + status + created_at + resolved_at + resolved_by + bump
+        seeds = [
+            b"redemption_request",
+            stablecoin_config.key().as_ref(),
+            &stablecoin_config.request_count.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        token::mint = mint,
+        token::authority = requester,
+        token::token_program = token_program,
+    )]
+    pub source: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: uninitialized when the requester was never blacklisted
+    #[account(
+        seeds = [b"blacklist", stablecoin_config.key().as_ref(), requester.key().as_ref()],
+        bump
+    )]
+    pub blacklist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: requester's allowlist entry, only read in allowlist mode
+    #[account(
+        seeds = [b"allowlist", stablecoin_config.key().as_ref(), requester.key().as_ref()],
+        bump
+    )]
+    pub allowlist_entry: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct ApproveRedemptionRequest<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"redemption_request",
+            stablecoin_config.key().as_ref(),
+            &request_id.to_le_bytes(),
+        ],
+        bump = redemption_request.bump
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+
+    #[account(
+        mut,
+        seeds = [b"stablecoin", mint.key().as_ref()],
+        bump = stablecoin_config.bump
+    )]
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
+
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        address = redemption_request.source,
+        constraint = source.owner == redemption_request.requester @ StablecoinError::UnauthorizedAccess,
+    )]
+    pub source: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: uninitialized when the requester was never blacklisted
+    #[account(
+        seeds = [b"blacklist", stablecoin_config.key().as_ref(), redemption_request.requester.as_ref()],
+        bump
+    )]
+    pub blacklist_entry: UncheckedAccount<'info>,
+
+    /// CHECK: requester's allowlist entry, only read in allowlist mode
+    #[account(
+        seeds = [b"allowlist", stablecoin_config.key().as_ref(), redemption_request.requester.as_ref()],
+        bump
+    )]
+    pub allowlist_entry: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Burner as u8],
+            authority.key().as_ref(),
+        ],
+        bump = burner_role.bump
+    )]
+    pub burner_role: Account<'info, RoleGrant>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
 }
 
 #[derive(Accounts)]
-pub struct SeizeTokens<'info> {
-    #[account(mut)]
-    pub mint: Account<'info, Mint>,
-
-    #[account(mut)]
-    pub from: Account<'info, TokenAccount>,
+#[instruction(request_id: u64)]
+pub struct RejectRedemptionRequest<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"redemption_request",
+            stablecoin_config.key().as_ref(),
+            &request_id.to_le_bytes(),
+        ],
+        bump = redemption_request.bump
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
 
-    #[account(mut)]
-    pub from_authority: Signer<'info>,
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
 
-    #[account(mut)]
-    pub treasury: Account<'info, TokenAccount>,
+    #[account(
+        seeds = [
+            b"role",
+            stablecoin_config.key().as_ref(),
+            &[Role::Burner as u8],
+            authority.key().as_ref(),
+        ],
+        bump = burner_role.bump
+    )]
+    pub burner_role: Account<'info, RoleGrant>,
 
-    #[account(mut)]
     pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(request_id: u64)]
+pub struct CancelRedemptionRequest<'info> {
+    #[account(
+        mut,
+        has_one = requester,
+        seeds = [
+            b"redemption_request",
+            stablecoin_config.key().as_ref(),
+            &request_id.to_le_bytes(),
+        ],
+        bump = redemption_request.bump
+    )]
+    pub redemption_request: Account<'info, RedemptionRequest>,
+
+    pub stablecoin_config: Account<'info, StablecoinConfig>,
 
-    pub token_program: Program<'info, token_2022::ID>,
+    pub requester: Signer<'info>,
 }
 
 #[derive(Accounts)]
-pub struct UpdateAuthority<'info> {
+pub struct AcceptAuthority<'info> {
     #[account(mut)]
     pub stablecoin_config: Account<'info, StablecoinConfig>,
 
-    #[account(mut)]
-    pub authority: Signer<'info>,
+    pub new_authority: Signer<'info>,
 }
 
 // ============================================================================
@@ -408,9 +5084,41 @@ pub struct StablecoinConfig {
     pub symbol: String,
     pub uri: String,
     pub decimals: u8,
-    pub mint_authority: Pubkey,
-    pub freeze_authority: Pubkey,
+    pub mint: Pubkey,
+    pub master_admin: Pubkey,
+    pub pending_master_admin: Option<Pubkey>,
     pub bump: u8,
+    /// Supply counters only see mints and burns made through this program;
+    /// holders can also burn directly with Token-2022, so the mint's `supply`
+    /// stays the authoritative figure for reconciliation
+    pub total_minted: u64,
+    pub total_burned: u64,
+    pub circulating_supply: u64,
+    pub paused: bool,
+    pub compliance_mode: ComplianceMode,
+    /// Receives the rent of closed blacklist entries
+    pub rent_collector: Pubkey,
+    /// Delay before a `ProposedAction` can execute, 0 disables the timelock
+    pub timelock_seconds: i64,
+    /// Id of the next `ProposedAction`
+    pub proposal_count: u64,
+    /// Token account receiving swept transfer fees
+    pub treasury: Option<Pubkey>,
+    /// Max age of the `ReserveAttestation` backing `mint_to`, always positive
+    pub reserve_staleness_seconds: i64,
+    /// Id of the next `MintRequest` / `RedemptionRequest`
+    pub request_count: u64,
+}
+
+/// Which list the transfer hook enforces
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ComplianceMode {
+    /// No list checks
+    Open,
+    /// SSS-2: every holder except blacklisted ones
+    Blacklist,
+    /// Privacy preset: only allowlisted (KYC'd) holders
+    Allowlist,
 }
 
 #[account]
@@ -418,4 +5126,984 @@ pub struct BlacklistEntry {
     pub address: Pubkey,
     pub is_blacklisted: bool,
     pub timestamp: i64,
+    /// Why the address was blacklisted (e.g. OFAC match, court order)
+    pub reason_code: Option<u16>,
+    /// Hash of the off-chain case file
+    pub case_reference: Option<[u8; 32]>,
+}
+
+#[account]
+pub struct AllowlistEntry {
+    pub address: Pubkey,
+    pub is_allowed: bool,
+    pub timestamp: i64,
+}
+
+/// Privileged roles granted per stablecoin through `RoleGrant` PDAs
+/// The master admin is held directly on `StablecoinConfig` and manages grants
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    /// `mint_to`
+    Minter,
+    /// `burn_from`
+    Burner,
+    /// Emergency stop
+    Pauser,
+    /// Blacklist management, `freeze_account` / `thaw_account`
+    Blacklister,
+    /// `seize_tokens`
+    Seizer,
+    /// `configure_minter` / `remove_minter`
+    MasterMinter,
+    /// `update_metadata`
+    MetadataAdmin,
+    /// Allowlist management, `approve_holder`, `approve_confidential_account`
+    Allowlister,
+    /// `cancel_action` on queued governance proposals
+    Guardian,
+    /// `update_transfer_fee`, `harvest_fees`, `withdraw_withheld_fees`
+    FeeAdmin,
+    /// `update_rate`
+    RateAuthority,
+    /// `attest_reserves`
+    Attestor,
+}
+
+/// Sensitive operations that are queued behind the timelock
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum GovernanceAction {
+    /// Nominate a new master admin, who still has to accept
+    TransferAuthority { new_master_admin: Pubkey },
+    /// Set a minter quota that exceeds the current one
+    ConfigureMinter { minter: Pubkey, allowance: u64, daily_cap: u64 },
+    /// Point the mint's TransferHook at another program, `None` removes it
+    UpdateTransferHookProgram { program_id: Option<Pubkey> },
+    /// Lift the emergency stop
+    Unpause,
+    /// Change the timelock delay itself
+    SetTimelock { timelock_seconds: i64 },
+    /// Grant a role; revocation is never delayed
+    GrantRole { role: Role, holder: Pubkey },
+    /// Switch the list the transfer hook enforces
+    SetComplianceMode { compliance_mode: ComplianceMode },
+    /// Toggle whether new token accounts start frozen
+    SetDefaultAccountState { frozen: bool },
+    /// Change where the rent of closed blacklist entries goes
+    SetRentCollector { rent_collector: Pubkey },
+    /// Toggle automatic approval of new confidential transfer accounts
+    SetConfidentialAutoApprove { auto_approve_new_accounts: bool },
+    /// Change the token account that receives swept transfer fees
+    SetTreasury { treasury: Pubkey },
+    /// Change the max age of the reserve attestation backing mints
+    SetReserveStaleness { reserve_staleness_seconds: i64 },
+}
+
+/// A queued `GovernanceAction`, closed when executed or cancelled
+#[account]
+pub struct ProposedAction {
+    pub stablecoin_config: Pubkey,
+    pub id: u64,
+    pub action: GovernanceAction,
+    /// Master admin at proposal time; only they can execute it
+    pub master_admin: Pubkey,
+    /// Paid the rent and receives it back on close
+    pub payer: Pubkey,
+    pub created_at: i64,
+    /// Earliest unix timestamp at which the action can execute
+    pub eta: i64,
+    pub bump: u8,
+}
+
+/// Built-in M-of-N signer set for admin operations, one per stablecoin
+#[account]
+pub struct AdminMultisig {
+    pub stablecoin_config: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    /// Id of the next `MultisigProposal`
+    pub nonce: u64,
+    /// Bumped on every signer set change, invalidating pending proposals
+    pub owner_set_seqno: u32,
+    pub bump: u8,
+}
+
+/// `AccountMeta` of a proposed instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MultisigAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// A call to this program awaiting multisig approval, closed on execution
+#[account]
+pub struct MultisigProposal {
+    pub multisig: Pubkey,
+    pub nonce: u64,
+    pub owner_set_seqno: u32,
+    /// Paid the rent and receives it back on close
+    pub proposer: Pubkey,
+    pub accounts: Vec<MultisigAccountMeta>,
+    pub data: Vec<u8>,
+    /// One flag per `AdminMultisig::signers` entry
+    pub approvals: Vec<bool>,
+    pub bump: u8,
+}
+
+/// TransferFeeConfig settings for `initialize`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct TransferFeeParams {
+    pub basis_points: u16,
+    /// Cap on the fee of a single transfer, in base units
+    pub maximum_fee: u64,
+}
+
+/// Lifecycle of a `MintRequest` / `RedemptionRequest`; only `Pending`
+/// requests can change status
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RequestStatus {
+    Pending,
+    Approved,
+    Rejected,
+    Cancelled,
+}
+
+/// A fiat-rail request to mint into `destination`, kept after resolution
+/// as an audit record
+#[account]
+pub struct MintRequest {
+    pub stablecoin_config: Pubkey,
+    pub id: u64,
+    pub requester: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    /// Off-chain wire or payment reference
+    pub wire_reference: [u8; 32],
+    pub status: RequestStatus,
+    pub created_at: i64,
+    pub resolved_at: i64,
+    pub resolved_by: Pubkey,
+    pub bump: u8,
+}
+
+/// A fiat-rail request to burn from `source` and pay out off-chain, kept
+/// after resolution as an audit record
+#[account]
+pub struct RedemptionRequest {
+    pub stablecoin_config: Pubkey,
+    pub id: u64,
+    pub requester: Pubkey,
+    pub source: Pubkey,
+    pub amount: u64,
+    /// Off-chain wire or payment reference
+    pub wire_reference: [u8; 32],
+    pub status: RequestStatus,
+    pub created_at: i64,
+    pub resolved_at: i64,
+    pub resolved_by: Pubkey,
+    pub bump: u8,
+}
+
+impl MintRequest {
+    pub fn resolve(&mut self, status: RequestStatus, resolved_by: Pubkey, now: i64) {
+        self.status = status;
+        self.resolved_by = resolved_by;
+        self.resolved_at = now;
+    }
+}
+
+impl RedemptionRequest {
+    pub fn resolve(&mut self, status: RequestStatus, resolved_by: Pubkey, now: i64) {
+        self.status = status;
+        self.resolved_by = resolved_by;
+        self.resolved_at = now;
+    }
+}
+
+/// Latest proof-of-reserves report, one per stablecoin
+#[account]
+pub struct ReserveAttestation {
+    pub stablecoin_config: Pubkey,
+    /// Attested reserves, in the stablecoin's base units
+    pub reserves: u64,
+    /// As-of unix timestamp of the report
+    pub attested_at: i64,
+    /// Hash of the off-chain attestation report
+    pub report_hash: [u8; 32],
+    pub attestor: Pubkey,
+    pub slot: u64,
+    pub bump: u8,
+}
+
+/// Number of rate changes kept by `RateHistory` before the oldest is dropped
+pub const RATE_HISTORY_CAPACITY: usize = 32;
+
+/// discriminator + config + total_updates + records + bump
+pub const RATE_HISTORY_SPACE: usize = 8 + 32 + 8 + (4 + (2 + 32 + 8 + 8) * RATE_HISTORY_CAPACITY) + 1;
+
+/// Interest rate changes of an interest-bearing stablecoin, oldest first
+#[account]
+pub struct RateHistory {
+    pub stablecoin_config: Pubkey,
+    /// Keeps counting past capacity
+    pub total_updates: u64,
+    pub records: Vec<RateRecord>,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct RateRecord {
+    /// Basis points per year
+    pub rate: i16,
+    pub updated_by: Pubkey,
+    pub slot: u64,
+    pub unix_timestamp: i64,
+}
+
+impl RateHistory {
+    pub fn append(&mut self, record: RateRecord) {
+        if self.records.len() == RATE_HISTORY_CAPACITY {
+            self.records.remove(0);
+        }
+        self.records.push(record);
+        self.total_updates += 1;
+    }
+}
+
+/// Additional TokenMetadata key/value pair
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MetadataField {
+    pub key: String,
+    pub value: String,
+}
+
+/// KYC approval for a holder, written by `approve_holder`
+#[account]
+pub struct HolderRecord {
+    pub stablecoin_config: Pubkey,
+    pub owner: Pubkey,
+    pub kyc_tier: u8,
+    /// ISO 3166-1 alpha-2 country code
+    pub jurisdiction: [u8; 2],
+    /// Unix timestamp after which the KYC must be renewed
+    /// Informational only: the program does not enforce it, so lapsed
+    /// holders have to be frozen by a Blacklister
+    pub expiry: i64,
+    pub approved_by: Pubkey,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct RoleGrant {
+    pub stablecoin_config: Pubkey,
+    pub role: Role,
+    pub holder: Pubkey,
+    pub granted_by: Pubkey,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+/// Per-minter quota, charged on every `mint_to`
+#[account]
+pub struct MinterInfo {
+    pub stablecoin_config: Pubkey,
+    pub minter: Pubkey,
+    /// Remaining amount this minter may mint
+    pub allowance: u64,
+    /// Max amount per rolling 24h window, 0 disables the cap
+    pub daily_cap: u64,
+    /// Hour index (unix time / 3600) of the most recent mint
+    pub last_bucket: i64,
+    /// Amount minted per hour over the last 24 hours
+    pub window_buckets: [u64; MINT_WINDOW_BUCKETS],
+    pub total_minted: u64,
+    pub bump: u8,
+}
+
+/// Number of records kept by `ComplianceLog` before the oldest is overwritten
+pub const COMPLIANCE_LOG_CAPACITY: usize = 64;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum ComplianceAction {
+    Blacklist,
+    Unblacklist,
+    Freeze,
+    Thaw,
+    Seize,
+    Allowlist,
+    Unallowlist,
+    ApproveHolder,
+    ApproveConfidentialAccount,
+}
+
+/// Append-only audit trail of compliance actions, one per stablecoin
+/// Fixed-size ring buffer: `total_records` keeps counting past capacity,
+/// so indexers can tell how many records were overwritten
+#[account(zero_copy)]
+pub struct ComplianceLog {
+    pub stablecoin_config: Pubkey,
+    pub total_records: u64,
+    pub records: [ComplianceRecord; COMPLIANCE_LOG_CAPACITY],
+}
+
+#[zero_copy]
+pub struct ComplianceRecord {
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    /// Hash of the off-chain case file, zeroed when none was given
+    pub case_reference: [u8; 32],
+    pub amount: u64,
+    pub slot: u64,
+    pub unix_timestamp: i64,
+    /// 0 when none was given
+    pub reason_code: u16,
+    /// `ComplianceAction` discriminant
+    pub action: u8,
+    pub _padding: [u8; 5],
+}
+
+impl ComplianceLog {
+    #[allow(clippy::too_many_arguments)]
+    pub fn append(
+        &mut self,
+        action: ComplianceAction,
+        actor: Pubkey,
+        target: Pubkey,
+        amount: u64,
+        reason_code: u16,
+        case_reference: [u8; 32],
+        clock: &Clock,
+    ) {
+        let index = (self.total_records % COMPLIANCE_LOG_CAPACITY as u64) as usize;
+        self.records[index] = ComplianceRecord {
+            actor,
+            target,
+            case_reference,
+            amount,
+            slot: clock.slot,
+            unix_timestamp: clock.unix_timestamp,
+            reason_code,
+            action: action as u8,
+            _padding: [0; 5],
+        };
+        self.total_records += 1;
+    }
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+// `actor` is the signer behind the action, `target` the affected account
+
+#[event]
+pub struct ConfigInitialized {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub decimals: u8,
+    pub slot: u64,
+}
+
+#[event]
+pub struct TransferHookInitialized {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct MetadataUpdated {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct Minted {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub amount: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct MintRequested {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub request_id: u64,
+    pub amount: u64,
+    pub wire_reference: [u8; 32],
+    pub slot: u64,
+}
+
+#[event]
+pub struct MintRequestResolved {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub request_id: u64,
+    pub status: RequestStatus,
+    pub slot: u64,
+}
+
+#[event]
+pub struct RedemptionRequested {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub request_id: u64,
+    pub amount: u64,
+    pub wire_reference: [u8; 32],
+    pub slot: u64,
+}
+
+#[event]
+pub struct RedemptionRequestResolved {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub request_id: u64,
+    pub status: RequestStatus,
+    pub slot: u64,
+}
+
+#[event]
+pub struct Burned {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub amount: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct Frozen {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub reason_code: Option<u16>,
+    pub case_reference: Option<[u8; 32]>,
+    pub slot: u64,
+}
+
+#[event]
+pub struct Thawed {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct Blacklisted {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub reason_code: Option<u16>,
+    pub case_reference: Option<[u8; 32]>,
+    pub slot: u64,
+}
+
+#[event]
+pub struct Unblacklisted {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct BlacklistEntryClosed {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub rent_collector: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct RentCollectorChanged {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct Allowlisted {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct Unallowlisted {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct HolderApproved {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub token_account: Pubkey,
+    pub kyc_tier: u8,
+    pub jurisdiction: [u8; 2],
+    pub expiry: i64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct ConfidentialAutoApproveChanged {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub auto_approve_new_accounts: bool,
+    pub slot: u64,
+}
+
+#[event]
+pub struct ConfidentialAccountApproved {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct TransferFeeUpdated {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub basis_points: u16,
+    pub maximum_fee: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct TreasuryChanged {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub amount: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct ReservesAttested {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub reserves: u64,
+    pub attested_at: i64,
+    pub report_hash: [u8; 32],
+    pub slot: u64,
+}
+
+#[event]
+pub struct ReserveStalenessChanged {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub reserve_staleness_seconds: i64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct RateUpdated {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub rate: i16,
+    pub slot: u64,
+}
+
+#[event]
+pub struct DefaultAccountStateChanged {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub frozen: bool,
+    pub slot: u64,
+}
+
+#[event]
+pub struct ComplianceModeChanged {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub compliance_mode: ComplianceMode,
+    pub slot: u64,
+}
+
+#[event]
+pub struct Seized {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub reason_code: Option<u16>,
+    pub case_reference: Option<[u8; 32]>,
+    pub slot: u64,
+}
+
+#[event]
+pub struct ConfidentialBalanceFrozen {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct MinterConfigured {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub allowance: u64,
+    pub daily_cap: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct MinterRemoved {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct PauseChanged {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub paused: bool,
+    pub slot: u64,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub role: Role,
+    pub slot: u64,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub role: Role,
+    pub slot: u64,
+}
+
+#[event]
+pub struct TimelockChanged {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub timelock_seconds: i64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct ActionProposed {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub action_id: u64,
+    pub action: GovernanceAction,
+    pub eta: i64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct ActionExecuted {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub action_id: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct ActionCancelled {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub action_id: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct TransferHookProgramUpdated {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub program_id: Option<Pubkey>,
+    pub slot: u64,
+}
+
+#[event]
+pub struct MultisigSignersChanged {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub slot: u64,
+}
+
+#[event]
+pub struct MultisigProposalCreated {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub nonce: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct MultisigProposalApproved {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub nonce: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct MultisigProposalExecuted {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub nonce: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct AuthorityChanged {
+    pub mint: Pubkey,
+    pub actor: Pubkey,
+    pub target: Pubkey,
+    pub slot: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = MINT_BUCKET_SECONDS;
+
+    fn minter_info(allowance: u64, daily_cap: u64) -> MinterInfo {
+        MinterInfo {
+            stablecoin_config: Pubkey::default(),
+            minter: Pubkey::default(),
+            allowance,
+            daily_cap,
+            last_bucket: 0,
+            window_buckets: [0; MINT_WINDOW_BUCKETS],
+            total_minted: 0,
+            bump: 0,
+        }
+    }
+
+    fn clock(slot: u64) -> Clock {
+        Clock {
+            slot,
+            unix_timestamp: slot as i64,
+            ..Clock::default()
+        }
+    }
+
+    #[test]
+    fn consume_charges_allowance_and_window() {
+        let mut info = minter_info(1_000, 500);
+        info.consume(200, 3 * HOUR).unwrap();
+
+        assert_eq!(info.allowance, 800);
+        assert_eq!(info.total_minted, 200);
+        assert_eq!(info.last_bucket, 3);
+        assert_eq!(info.window_buckets[3], 200);
+    }
+
+    #[test]
+    fn consume_rejects_exhausted_allowance() {
+        let mut info = minter_info(100, 0);
+        info.consume(100, 0).unwrap();
+
+        assert_eq!(info.allowance, 0);
+        assert_eq!(
+            info.consume(1, 0).unwrap_err(),
+            StablecoinError::MinterAllowanceExceeded.into()
+        );
+    }
+
+    #[test]
+    fn consume_allows_daily_cap_exactly() {
+        let mut info = minter_info(1_000, 100);
+        info.consume(60, 0).unwrap();
+        info.consume(40, 5 * HOUR).unwrap();
+
+        assert_eq!(
+            info.consume(1, 23 * HOUR).unwrap_err(),
+            StablecoinError::MinterDailyCapExceeded.into()
+        );
+        assert_eq!(info.allowance, 900);
+    }
+
+    #[test]
+    fn roll_window_only_frees_expired_buckets() {
+        let mut info = minter_info(1_000, 100);
+        info.consume(60, 0).unwrap();
+        info.consume(40, 12 * HOUR).unwrap();
+
+        // Hour 24 reuses the bucket of hour 0; hour 12 is still in the window
+        info.roll_window(24 * HOUR);
+        assert_eq!(info.window_buckets[0], 0);
+        assert_eq!(info.window_buckets[12], 40);
+
+        assert_eq!(
+            info.consume(61, 24 * HOUR).unwrap_err(),
+            StablecoinError::MinterDailyCapExceeded.into()
+        );
+        info.consume(60, 24 * HOUR).unwrap();
+    }
+
+    #[test]
+    fn roll_window_clears_everything_after_a_full_window() {
+        let mut info = minter_info(1_000, 100);
+        for hour in 0..MINT_WINDOW_BUCKETS as i64 {
+            info.window_buckets[hour as usize] = 1;
+        }
+        info.last_bucket = 5;
+
+        info.roll_window(100 * HOUR);
+
+        assert_eq!(info.window_buckets, [0; MINT_WINDOW_BUCKETS]);
+        assert_eq!(info.last_bucket, 100);
+    }
+
+    #[test]
+    fn quota_increase_detection() {
+        let capped = minter_info(1_000, 100);
+        assert!(!capped.is_quota_increase(1_000, 100));
+        assert!(!capped.is_quota_increase(500, 50));
+        assert!(capped.is_quota_increase(1_001, 100));
+        assert!(capped.is_quota_increase(1_000, 101));
+        // Removing the cap lifts the limit
+        assert!(capped.is_quota_increase(1_000, 0));
+
+        let uncapped = minter_info(1_000, 0);
+        assert!(!uncapped.is_quota_increase(1_000, 0));
+        assert!(!uncapped.is_quota_increase(1_000, u64::MAX));
+        assert!(uncapped.is_quota_increase(2_000, 0));
+    }
+
+    #[test]
+    fn compliance_log_wraps_around() {
+        let empty = ComplianceRecord {
+            actor: Pubkey::default(),
+            target: Pubkey::default(),
+            case_reference: [0; 32],
+            amount: 0,
+            slot: 0,
+            unix_timestamp: 0,
+            reason_code: 0,
+            action: 0,
+            _padding: [0; 5],
+        };
+        let mut log = ComplianceLog {
+            stablecoin_config: Pubkey::default(),
+            total_records: 0,
+            records: [empty; COMPLIANCE_LOG_CAPACITY],
+        };
+
+        for amount in 0..=COMPLIANCE_LOG_CAPACITY as u64 {
+            log.append(
+                ComplianceAction::Freeze,
+                Pubkey::default(),
+                Pubkey::default(),
+                amount,
+                7,
+                [1; 32],
+                &clock(amount),
+            );
+        }
+
+        assert_eq!(log.total_records, COMPLIANCE_LOG_CAPACITY as u64 + 1);
+        // The newest record replaced the oldest one
+        assert_eq!(log.records[0].amount, COMPLIANCE_LOG_CAPACITY as u64);
+        assert_eq!(log.records[0].slot, COMPLIANCE_LOG_CAPACITY as u64);
+        assert_eq!(log.records[1].amount, 1);
+        assert_eq!(log.records[COMPLIANCE_LOG_CAPACITY - 1].amount, COMPLIANCE_LOG_CAPACITY as u64 - 1);
+        assert_eq!(log.records[0].action, ComplianceAction::Freeze as u8);
+        assert_eq!(log.records[0].reason_code, 7);
+        assert_eq!(log.records[0].case_reference, [1; 32]);
+    }
+
+    #[test]
+    fn rate_history_drops_oldest_past_capacity() {
+        let mut history = RateHistory {
+            stablecoin_config: Pubkey::default(),
+            total_updates: 0,
+            records: Vec::new(),
+            bump: 0,
+        };
+
+        for rate in 0..=RATE_HISTORY_CAPACITY as i16 {
+            history.append(RateRecord {
+                rate,
+                updated_by: Pubkey::default(),
+                slot: rate as u64,
+                unix_timestamp: rate as i64,
+            });
+        }
+
+        assert_eq!(history.total_updates, RATE_HISTORY_CAPACITY as u64 + 1);
+        assert_eq!(history.records.len(), RATE_HISTORY_CAPACITY);
+        assert_eq!(history.records[0].rate, 1);
+        assert_eq!(history.records.last().unwrap().rate, RATE_HISTORY_CAPACITY as i16);
+    }
+
+    #[test]
+    fn multisig_validation() {
+        let signers: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        assert!(assert_valid_multisig(&signers, 1).is_ok());
+        assert!(assert_valid_multisig(&signers, 3).is_ok());
+
+        let invalid: Error = StablecoinError::InvalidMultisig.into();
+        assert_eq!(assert_valid_multisig(&signers, 0).unwrap_err(), invalid);
+        assert_eq!(assert_valid_multisig(&signers, 4).unwrap_err(), invalid);
+        assert_eq!(assert_valid_multisig(&[], 0).unwrap_err(), invalid);
+
+        let too_many: Vec<Pubkey> = (0..=MAX_MULTISIG_SIGNERS).map(|_| Pubkey::new_unique()).collect();
+        assert_eq!(assert_valid_multisig(&too_many, 1).unwrap_err(), invalid);
+
+        let duplicated = [signers[0], signers[1], signers[0]];
+        assert_eq!(assert_valid_multisig(&duplicated, 2).unwrap_err(), invalid);
+    }
 }
@@ -5,249 +5,1500 @@ import {
   Connection,
   PublicKey,
   Keypair,
-  Transaction,
-  TransactionInstruction,
   SystemProgram,
-  SYSVAR_RENT_PUBKEY,
+  TransactionInstruction,
 } from '@solana/web3.js';
+import {
+  TOKEN_2022_PROGRAM_ID,
+  createTransferCheckedWithTransferHookInstruction,
+  getAccount,
+} from '@solana/spl-token';
 import { Program, AnchorProvider, web3 } from '@project-serum/anchor';
 
-// ID do programa (gerado automaticamente pelo Anchor)
-const STABLECOIN_PROGRAM_ID = new PublicKey('STABLE111111111111111111111111111111111');
+// ID do programa (declare_id! em programs/solana-stablecoin-standard/src/lib.rs)
+const STABLECOIN_PROGRAM_ID = new PublicKey('C1ez3z2tAjAvQabEVPnYeStfYH65842ghYd97Bprw71m');
+
+// ============================================================================
+// Interfaces
+// ============================================================================
+
+/** Privileged roles, in the on-chain `Role` order used by the role PDA seeds */
+export enum Role {
+  Minter,
+  Burner,
+  Pauser,
+  Blacklister,
+  Seizer,
+  MasterMinter,
+  MetadataAdmin,
+  Allowlister,
+  Guardian,
+  FeeAdmin,
+  RateAuthority,
+  Attestor,
+}
+
+/** Which list the transfer hook enforces */
+export type ComplianceMode = 'open' | 'blacklist' | 'allowlist';
+
+export interface StablecoinConfig {
+  name: string;
+  symbol: string;
+  uri: string;
+  decimals: number;
+  master_admin: PublicKey;
+  pending_master_admin: PublicKey | null;
+  paused: boolean;
+  compliance_mode: ComplianceMode;
+  rent_collector: PublicKey;
+  timelock_seconds: number; // 0 = sensitive operations apply immediately
+  proposal_count: number;
+  treasury: PublicKey | null; // receives swept transfer fees
+  reserve_staleness_seconds: number; // max age of the reserve attestation
+  request_count: number; // id of the next mint or redemption request
+  // Program-mediated mints and burns only; the mint's supply is authoritative
+  total_minted: bigint;
+  total_burned: bigint;
+  circulating_supply: bigint;
+}
+
+export interface BlacklistEntry {
+  address: PublicKey;
+  is_blacklisted: boolean;
+  timestamp: number;
+  reason_code: number | null;
+  case_reference: number[] | null;
+}
+
+/** On-chain `ComplianceAction` order */
+export enum ComplianceAction {
+  Blacklist,
+  Unblacklist,
+  Freeze,
+  Thaw,
+  Seize,
+  Allowlist,
+  Unallowlist,
+  ApproveHolder,
+  ApproveConfidentialAccount,
+}
+
+export interface ComplianceRecord {
+  action: ComplianceAction;
+  actor: PublicKey;
+  target: PublicKey;
+  amount: bigint;
+  reason_code: number;
+  case_reference: number[];
+  slot: bigint;
+  unix_timestamp: number;
+}
+
+export interface TransferFeeParams {
+  basis_points: number; // 0-10000
+  maximum_fee: number; // per transfer, in base units
+}
+
+export interface InitializeParams {
+  name: string;
+  symbol: string;
+  uri: string;
+  decimals: number; // 0-9, 6 is typical for fiat stablecoins
+  compliance_mode: ComplianceMode;
+  // New token accounts start frozen until `approveHolder` thaws them
+  default_account_frozen: boolean;
+  // 32-byte ElGamal key of the auditor; enables confidential transfers
+  auditor_elgamal_pubkey?: number[];
+  // Withheld on every transfer; cannot be combined with an auditor key
+  transfer_fee?: TransferFeeParams;
+  interest_rate?: number; // basis points per year, only affects UI amounts
+  // Max age of the reserve attestation backing mints, must be positive
+  reserve_staleness_seconds: number;
+}
+
+export interface RateRecord {
+  rate: number; // basis points per year
+  updated_by: PublicKey;
+  slot: bigint;
+  unix_timestamp: number;
+}
+
+export interface AttestReservesParams {
+  reserves: number; // in base units
+  attested_at: number; // unix timestamp of the report, cannot go backwards
+  report_hash: number[]; // 32-byte hash of the off-chain report
+}
+
+export interface ReserveAttestation {
+  reserves: bigint;
+  attested_at: number;
+  report_hash: number[];
+  attestor: PublicKey;
+  slot: bigint;
+}
+
+/** Only `pending` requests can change status */
+export type RequestStatus = 'pending' | 'approved' | 'rejected' | 'cancelled';
+
+export interface RequestMintParams {
+  destination: PublicKey; // token account
+  amount: number;
+  wire_reference: number[]; // 32-byte off-chain wire or payment reference
+}
+
+export interface RequestRedemptionParams {
+  source: PublicKey; // token account owned by the wallet
+  amount: number;
+  wire_reference: number[];
+}
+
+// A mint request targets `destination`, a redemption request burns from `source`
+export interface FiatRequest {
+  id: number;
+  requester: PublicKey;
+  account: PublicKey;
+  amount: bigint;
+  wire_reference: number[];
+  status: RequestStatus;
+  created_at: number;
+  resolved_at: number;
+  resolved_by: PublicKey;
+}
+
+export interface MintToParams {
+  to: PublicKey; // token account
+  amount: number;
+}
+
+export interface BurnParams {
+  from: PublicKey; // token account
+  amount: number;
+}
+
+// `reason_code` (e.g. OFAC match, court order) and the 32-byte hash of the
+// off-chain case file are stored in the compliance log
+/** Max targets per batch instruction, mirrors `MAX_BATCH_SIZE` */
+export const MAX_BATCH_SIZE = 20;
+
+export interface BatchBlacklistParams {
+  addresses: PublicKey[];
+  reason_code?: number;
+  case_reference?: number[];
+}
+
+export interface BatchFreezeParams {
+  accounts: PublicKey[]; // token accounts
+  reason_code?: number;
+  case_reference?: number[];
+}
+
+export interface ModifyAllowlistParams {
+  address: PublicKey;
+}
+
+export interface ApproveHolderParams {
+  account: PublicKey; // token account to thaw
+  kyc_tier: number;
+  jurisdiction: string; // ISO 3166-1 alpha-2, e.g. 'US'
+  expiry: number; // unix timestamp, informational only
+}
+
+export interface FreezeAccountParams {
+  account: PublicKey;
+  reason_code?: number;
+  case_reference?: number[];
+}
+
+export interface ModifyBlacklistParams {
+  address: PublicKey;
+  reason_code?: number;
+  case_reference?: number[];
+}
+
+export interface SeizeTokensParams {
+  from: PublicKey;
+  treasury: PublicKey;
+  amount: number;
+  reason_code?: number;
+  case_reference?: number[];
+}
+
+export interface RoleParams {
+  role: Role;
+  holder: PublicKey;
+}
+
+export interface ConfigureMinterParams {
+  minter: PublicKey;
+  allowance: number;
+  daily_cap: number; // 0 disables the rolling 24h cap
+}
+
+export interface UpdateMetadataParams {
+  name?: string;
+  symbol?: string;
+  uri?: string;
+  // Upserted as TokenMetadata additional fields, e.g. `issuer`
+  additional_metadata?: { key: string; value: string }[];
+}
+
+export interface UpdateAuthorityParams {
+  newAuthority: PublicKey;
+}
+
+/** Sensitive operations queued behind the timelock, mirrors `GovernanceAction` */
+export type GovernanceAction =
+  | { kind: 'transferAuthority'; new_master_admin: PublicKey }
+  | { kind: 'configureMinter'; minter: PublicKey; allowance: number; daily_cap: number }
+  | { kind: 'updateTransferHookProgram'; program_id: PublicKey | null }
+  | { kind: 'unpause' }
+  | { kind: 'setTimelock'; timelock_seconds: number }
+  | { kind: 'grantRole'; role: Role; holder: PublicKey }
+  | { kind: 'setComplianceMode'; compliance_mode: ComplianceMode }
+  | { kind: 'setDefaultAccountState'; frozen: boolean }
+  | { kind: 'setRentCollector'; rent_collector: PublicKey }
+  | { kind: 'setConfidentialAutoApprove'; auto_approve_new_accounts: boolean }
+  | { kind: 'setTreasury'; treasury: PublicKey }
+  | { kind: 'setReserveStaleness'; reserve_staleness_seconds: number };
+
+// ============================================================================
+// PDAs
+// ============================================================================
+
+export function findConfigPda(mint: PublicKey, programId = STABLECOIN_PROGRAM_ID): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('stablecoin'), mint.toBuffer()],
+    programId
+  )[0];
+}
+
+export function findRolePda(
+  config: PublicKey,
+  role: Role,
+  holder: PublicKey,
+  programId = STABLECOIN_PROGRAM_ID
+): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('role'), config.toBuffer(), Buffer.from([role]), holder.toBuffer()],
+    programId
+  )[0];
+}
+
+export function findMinterPda(config: PublicKey, minter: PublicKey, programId = STABLECOIN_PROGRAM_ID): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('minter'), config.toBuffer(), minter.toBuffer()],
+    programId
+  )[0];
+}
+
+export function findBlacklistPda(config: PublicKey, address: PublicKey, programId = STABLECOIN_PROGRAM_ID): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('blacklist'), config.toBuffer(), address.toBuffer()],
+    programId
+  )[0];
+}
+
+export function findAllowlistPda(config: PublicKey, address: PublicKey, programId = STABLECOIN_PROGRAM_ID): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('allowlist'), config.toBuffer(), address.toBuffer()],
+    programId
+  )[0];
+}
+
+export function findHolderPda(config: PublicKey, owner: PublicKey, programId = STABLECOIN_PROGRAM_ID): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('holder'), config.toBuffer(), owner.toBuffer()],
+    programId
+  )[0];
+}
+
+export function findComplianceLogPda(config: PublicKey, programId = STABLECOIN_PROGRAM_ID): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('compliance_log'), config.toBuffer()],
+    programId
+  )[0];
+}
+
+export function findExtraAccountMetasPda(mint: PublicKey, programId = STABLECOIN_PROGRAM_ID): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('extra-account-metas'), mint.toBuffer()],
+    programId
+  )[0];
+}
+
+export function findProposalPda(config: PublicKey, actionId: number, programId = STABLECOIN_PROGRAM_ID): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('proposal'), config.toBuffer(), new web3.BN(actionId).toArrayLike(Buffer, 'le', 8)],
+    programId
+  )[0];
+}
+
+export function findRateHistoryPda(config: PublicKey, programId = STABLECOIN_PROGRAM_ID): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('rate_history'), config.toBuffer()],
+    programId
+  )[0];
+}
+
+export function findReservesPda(config: PublicKey, programId = STABLECOIN_PROGRAM_ID): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('reserves'), config.toBuffer()],
+    programId
+  )[0];
+}
+
+export function findMintRequestPda(config: PublicKey, requestId: number, programId = STABLECOIN_PROGRAM_ID): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('mint_request'), config.toBuffer(), new web3.BN(requestId).toArrayLike(Buffer, 'le', 8)],
+    programId
+  )[0];
+}
+
+export function findRedemptionRequestPda(config: PublicKey, requestId: number, programId = STABLECOIN_PROGRAM_ID): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('redemption_request'), config.toBuffer(), new web3.BN(requestId).toArrayLike(Buffer, 'le', 8)],
+    programId
+  )[0];
+}
+
+export function findMultisigPda(config: PublicKey, programId = STABLECOIN_PROGRAM_ID): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('multisig'), config.toBuffer()],
+    programId
+  )[0];
+}
+
+export function findMultisigProposalPda(multisig: PublicKey, nonce: number, programId = STABLECOIN_PROGRAM_ID): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('multisig_proposal'), multisig.toBuffer(), new web3.BN(nonce).toArrayLike(Buffer, 'le', 8)],
+    programId
+  )[0];
+}
+
+/** Anchor enum argument, e.g. `{ masterMinter: {} }` */
+export function roleArg(role: Role): Record<string, object> {
+  const name = Role[role];
+  return { [name.charAt(0).toLowerCase() + name.slice(1)]: {} };
+}
+
+function complianceModeArg(mode: ComplianceMode): Record<string, object> {
+  return { [mode]: {} };
+}
+
+/** Inverse of `roleArg` */
+function roleFromArg(arg: Record<string, object>): Role {
+  const name = Object.keys(arg)[0];
+  return Role[(name.charAt(0).toUpperCase() + name.slice(1)) as keyof typeof Role];
+}
+
+function governanceActionArg(action: GovernanceAction): Record<string, object> {
+  switch (action.kind) {
+    case 'transferAuthority':
+      return { transferAuthority: { newMasterAdmin: action.new_master_admin } };
+    case 'configureMinter':
+      return {
+        configureMinter: {
+          minter: action.minter,
+          allowance: new web3.BN(action.allowance),
+          dailyCap: new web3.BN(action.daily_cap),
+        },
+      };
+    case 'updateTransferHookProgram':
+      return { updateTransferHookProgram: { programId: action.program_id } };
+    case 'unpause':
+      return { unpause: {} };
+    case 'setTimelock':
+      return { setTimelock: { timelockSeconds: new web3.BN(action.timelock_seconds) } };
+    case 'grantRole':
+      return { grantRole: { role: roleArg(action.role), holder: action.holder } };
+    case 'setComplianceMode':
+      return { setComplianceMode: { complianceMode: complianceModeArg(action.compliance_mode) } };
+    case 'setDefaultAccountState':
+      return { setDefaultAccountState: { frozen: action.frozen } };
+    case 'setRentCollector':
+      return { setRentCollector: { rentCollector: action.rent_collector } };
+    case 'setConfidentialAutoApprove':
+      return { setConfidentialAutoApprove: { autoApproveNewAccounts: action.auto_approve_new_accounts } };
+    case 'setTreasury':
+      return { setTreasury: { treasury: action.treasury } };
+    case 'setReserveStaleness':
+      return {
+        setReserveStaleness: { reserveStalenessSeconds: new web3.BN(action.reserve_staleness_seconds) },
+      };
+  }
+}
+
+function fiatRequestFromAccount(request: any, account: PublicKey): FiatRequest {
+  return {
+    id: request.id.toNumber(),
+    requester: request.requester,
+    account,
+    amount: BigInt(request.amount.toString()),
+    wire_reference: request.wireReference,
+    status: Object.keys(request.status)[0] as RequestStatus,
+    created_at: request.createdAt.toNumber(),
+    resolved_at: request.resolvedAt.toNumber(),
+    resolved_by: request.resolvedBy,
+  };
+}
+
+// ============================================================================
+// StablecoinSDK Class
+// ============================================================================
+
+export class StablecoinSDK {
+  public program: Program;
+  public programId: PublicKey;
+  public mint?: PublicKey;
+
+  constructor(
+    provider: AnchorProvider,
+    idl: any,
+    programId: PublicKey = STABLECOIN_PROGRAM_ID,
+    mint?: PublicKey
+  ) {
+    this.programId = programId;
+    this.program = new Program(idl, programId, provider);
+    this.mint = mint;
+  }
+
+  private get wallet(): PublicKey {
+    return this.program.provider.wallet.publicKey;
+  }
+
+  private requireMint(): PublicKey {
+    if (!this.mint) {
+      throw new Error('No mint: call initialize() or pass the mint to the SDK');
+    }
+    return this.mint;
+  }
+
+  private get config(): PublicKey {
+    return findConfigPda(this.requireMint(), this.programId);
+  }
+
+  /**
+   * Initialize SSS-2 Compliant Stablecoin
+   * Creates the Token-2022 mint with the transfer hook, then the hook's
+   * extra account metas so every transfer is checked against the blacklist.
+   * The wallet becomes the master admin
+   */
+  async initialize(params: InitializeParams): Promise<string> {
+    const mint = Keypair.generate();
+    const stablecoinConfig = findConfigPda(mint.publicKey, this.programId);
+
+    await this.program.methods
+      .initialize(
+        params.name,
+        params.symbol,
+        params.uri,
+        params.decimals,
+        complianceModeArg(params.compliance_mode),
+        params.default_account_frozen,
+        params.auditor_elgamal_pubkey ?? null,
+        params.transfer_fee
+          ? {
+              basisPoints: params.transfer_fee.basis_points,
+              maximumFee: new web3.BN(params.transfer_fee.maximum_fee),
+            }
+          : null,
+        params.interest_rate ?? null,
+        new web3.BN(params.reserve_staleness_seconds)
+      )
+      .accounts({
+        stablecoinConfig,
+        mint: mint.publicKey,
+        rateHistory: params.interest_rate !== undefined
+          ? findRateHistoryPda(stablecoinConfig, this.programId)
+          : null,
+        complianceLog: findComplianceLogPda(stablecoinConfig, this.programId),
+        authority: this.wallet,
+        payer: this.wallet,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([mint])
+      .rpc();
+
+    const tx = await this.program.methods
+      .initializeExtraAccountMetaList()
+      .accounts({
+        extraAccountMetaList: findExtraAccountMetasPda(mint.publicKey, this.programId),
+        mint: mint.publicKey,
+        stablecoinConfig,
+        authority: this.wallet,
+        payer: this.wallet,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    this.mint = mint.publicKey;
+    console.log('Stablecoin initialized:', mint.publicKey.toBase58());
+    return tx;
+  }
+
+  /**
+   * Grant a role (master admin only)
+   */
+  async grantRole(params: RoleParams): Promise<string> {
+    const tx = await this.program.methods
+      .grantRole(roleArg(params.role), params.holder)
+      .accounts({
+        roleGrant: findRolePda(this.config, params.role, params.holder, this.programId),
+        stablecoinConfig: this.config,
+        authority: this.wallet,
+        payer: this.wallet,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Revoke a role (master admin only)
+   */
+  async revokeRole(params: RoleParams): Promise<string> {
+    const tx = await this.program.methods
+      .revokeRole(roleArg(params.role), params.holder)
+      .accounts({
+        roleGrant: findRolePda(this.config, params.role, params.holder, this.programId),
+        stablecoinConfig: this.config,
+        authority: this.wallet,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Set a minter's allowance and rolling 24h cap (master minter only)
+   */
+  async configureMinter(params: ConfigureMinterParams): Promise<string> {
+    const tx = await this.program.methods
+      .configureMinter(params.minter, new web3.BN(params.allowance), new web3.BN(params.daily_cap))
+      .accounts({
+        minterInfo: findMinterPda(this.config, params.minter, this.programId),
+        stablecoinConfig: this.config,
+        masterMinterRole: findRolePda(this.config, Role.MasterMinter, this.wallet, this.programId),
+        authority: this.wallet,
+        payer: this.wallet,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Mint new tokens to a recipient token account
+   * Only callable by a minter, within its quota and the attested reserves
+   */
+  async mintTo(params: MintToParams): Promise<string> {
+    const mint = this.requireMint();
+    // Allowlist mode checks the recipient owner's entry
+    const to = await getAccount(
+      this.program.provider.connection,
+      params.to,
+      'confirmed',
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    const tx = await this.program.methods
+      .mintTo(new web3.BN(params.amount))
+      .accounts({
+        stablecoinConfig: this.config,
+        mint,
+        to: params.to,
+        allowlistEntry: findAllowlistPda(this.config, to.owner, this.programId),
+        reserveAttestation: findReservesPda(this.config, this.programId),
+        minterInfo: findMinterPda(this.config, this.wallet, this.programId),
+        minterRole: findRolePda(this.config, Role.Minter, this.wallet, this.programId),
+        authority: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Burn tokens from the wallet's own token account (holder redemption)
+   */
+  async burn(params: BurnParams): Promise<string> {
+    const tx = await this.program.methods
+      .burn(new web3.BN(params.amount))
+      .accounts({
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        from: params.from,
+        owner: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Burn tokens from a holder's token account (burner only)
+   * The holder must first approve the wallet as delegate for `amount`
+   */
+  async burnFrom(params: BurnParams): Promise<string> {
+    const tx = await this.program.methods
+      .burnFrom(new web3.BN(params.amount))
+      .accounts({
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        from: params.from,
+        burnerRole: findRolePda(this.config, Role.Burner, this.wallet, this.programId),
+        authority: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Emergency stop: halts minting, burning, seizure and transfers (pauser only)
+   */
+  async pause(): Promise<string> {
+    return this.program.methods.pause().accounts(this.setPausedAccounts()).rpc();
+  }
+
+  /**
+   * Lift the emergency stop (pauser only)
+   */
+  async unpause(): Promise<string> {
+    return this.program.methods.unpause().accounts(this.setPausedAccounts()).rpc();
+  }
+
+  private setPausedAccounts() {
+    return {
+      stablecoinConfig: this.config,
+      pauserRole: findRolePda(this.config, Role.Pauser, this.wallet, this.programId),
+      authority: this.wallet,
+    };
+  }
+
+  /**
+   * Update the on-mint TokenMetadata (metadata admin only)
+   */
+  async updateMetadata(params: UpdateMetadataParams): Promise<string> {
+    const tx = await this.program.methods
+      .updateMetadata(
+        params.name ?? null,
+        params.symbol ?? null,
+        params.uri ?? null,
+        params.additional_metadata ?? []
+      )
+      .accounts({
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        metadataAdminRole: findRolePda(this.config, Role.MetadataAdmin, this.wallet, this.programId),
+        authority: this.wallet,
+        payer: this.wallet,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Freeze a token account (blacklister only)
+   */
+  async freezeAccount(params: FreezeAccountParams): Promise<string> {
+    const tx = await this.program.methods
+      .freezeAccount(params.reason_code ?? null, params.case_reference ?? null)
+      .accounts({
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        tokenAccount: params.account,
+        complianceLog: findComplianceLogPda(this.config, this.programId),
+        blacklisterRole: findRolePda(this.config, Role.Blacklister, this.wallet, this.programId),
+        authority: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Thaw a frozen token account (blacklister only)
+   */
+  async thawAccount(params: FreezeAccountParams): Promise<string> {
+    const tx = await this.program.methods
+      .thawAccount()
+      .accounts({
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        tokenAccount: params.account,
+        complianceLog: findComplianceLogPda(this.config, this.programId),
+        blacklisterRole: findRolePda(this.config, Role.Blacklister, this.wallet, this.programId),
+        authority: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Add an address to blacklist (blacklister only)
+   * Stores blacklist status in the PDA read by the Transfer Hook
+   */
+  async addToBlacklist(params: ModifyBlacklistParams): Promise<string> {
+    const tx = await this.program.methods
+      .addToBlacklist(params.address, params.reason_code ?? null, params.case_reference ?? null)
+      .accounts(this.modifyBlacklistAccounts(params.address))
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Remove an address from blacklist (blacklister only)
+   */
+  async removeFromBlacklist(params: ModifyBlacklistParams): Promise<string> {
+    const tx = await this.program.methods
+      .removeFromBlacklist(params.address)
+      .accounts(this.removeBlacklistAccounts(params.address))
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Close a cleared blacklist entry and send its rent to the configured
+   * rent collector (blacklister only)
+   */
+  async closeBlacklistEntry(address: PublicKey): Promise<string> {
+    const { rent_collector } = await this.getConfig();
+    const tx = await this.program.methods
+      .closeBlacklistEntry(address)
+      .accounts({
+        blacklistEntry: findBlacklistPda(this.config, address, this.programId),
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        rentCollector: rent_collector,
+        blacklisterRole: findRolePda(this.config, Role.Blacklister, this.wallet, this.programId),
+        authority: this.wallet,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  private modifyBlacklistAccounts(address: PublicKey) {
+    return {
+      ...this.removeBlacklistAccounts(address),
+      payer: this.wallet,
+      systemProgram: SystemProgram.programId,
+    };
+  }
+
+  private removeBlacklistAccounts(address: PublicKey) {
+    return {
+      blacklistEntry: findBlacklistPda(this.config, address, this.programId),
+      stablecoinConfig: this.config,
+      mint: this.requireMint(),
+      complianceLog: findComplianceLogPda(this.config, this.programId),
+      blacklisterRole: findRolePda(this.config, Role.Blacklister, this.wallet, this.programId),
+      authority: this.wallet,
+    };
+  }
+  /**
+   * Blacklist up to `MAX_BATCH_SIZE` addresses in one instruction (blacklister only)
+   */
+  async batchAddToBlacklist(params: BatchBlacklistParams): Promise<string> {
+    const tx = await this.program.methods
+      .batchAddToBlacklist(params.addresses, params.reason_code ?? null, params.case_reference ?? null)
+      .accounts(this.batchBlacklistAccounts())
+      .remainingAccounts(this.blacklistEntryMetas(params.addresses))
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Remove up to `MAX_BATCH_SIZE` addresses from the blacklist (blacklister only)
+   */
+  async batchRemoveFromBlacklist(params: BatchBlacklistParams): Promise<string> {
+    const tx = await this.program.methods
+      .batchRemoveFromBlacklist(params.addresses)
+      .accounts(this.batchBlacklistAccounts())
+      .remainingAccounts(this.blacklistEntryMetas(params.addresses))
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Freeze up to `MAX_BATCH_SIZE` token accounts in one instruction (blacklister only)
+   * Accounts that are already frozen are skipped
+   */
+  async batchFreeze(params: BatchFreezeParams): Promise<string> {
+    const tx = await this.program.methods
+      .batchFreeze(params.accounts, params.reason_code ?? null, params.case_reference ?? null)
+      .accounts({
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        complianceLog: findComplianceLogPda(this.config, this.programId),
+        blacklisterRole: findRolePda(this.config, Role.Blacklister, this.wallet, this.programId),
+        authority: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(
+        params.accounts.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+      )
+      .rpc();
+
+    return tx;
+  }
+
+  private batchBlacklistAccounts() {
+    return {
+      stablecoinConfig: this.config,
+      mint: this.requireMint(),
+      complianceLog: findComplianceLogPda(this.config, this.programId),
+      blacklisterRole: findRolePda(this.config, Role.Blacklister, this.wallet, this.programId),
+      authority: this.wallet,
+      payer: this.wallet,
+      systemProgram: SystemProgram.programId,
+    };
+  }
+
+  private blacklistEntryMetas(addresses: PublicKey[]) {
+    return addresses.map((address) => ({
+      pubkey: findBlacklistPda(this.config, address, this.programId),
+      isSigner: false,
+      isWritable: true,
+    }));
+  }
+
+  /**
+   * Add an address to the allowlist (allowlister only)
+   * Only enforced while the stablecoin runs in allowlist mode
+   */
+  async addToAllowlist(params: ModifyAllowlistParams): Promise<string> {
+    const tx = await this.program.methods
+      .addToAllowlist(params.address)
+      .accounts(this.modifyAllowlistAccounts(params.address))
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Remove an address from the allowlist (allowlister only)
+   */
+  async removeFromAllowlist(params: ModifyAllowlistParams): Promise<string> {
+    const tx = await this.program.methods
+      .removeFromAllowlist(params.address)
+      .accounts(this.modifyAllowlistAccounts(params.address))
+      .rpc();
+
+    return tx;
+  }
+
+  private modifyAllowlistAccounts(address: PublicKey) {
+    return {
+      allowlistEntry: findAllowlistPda(this.config, address, this.programId),
+      stablecoinConfig: this.config,
+      mint: this.requireMint(),
+      complianceLog: findComplianceLogPda(this.config, this.programId),
+      allowlisterRole: findRolePda(this.config, Role.Allowlister, this.wallet, this.programId),
+      authority: this.wallet,
+      payer: this.wallet,
+      systemProgram: SystemProgram.programId,
+    };
+  }
+
+  /**
+   * Record a holder's KYC approval and thaw the given token account (allowlister only)
+   * Blacklisted holders are refused
+   */
+  async approveHolder(params: ApproveHolderParams): Promise<string> {
+    const account = await getAccount(
+      this.program.provider.connection,
+      params.account,
+      'confirmed',
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    const tx = await this.program.methods
+      .approveHolder(params.kyc_tier, Array.from(Buffer.from(params.jurisdiction, 'ascii')), new web3.BN(params.expiry))
+      .accounts({
+        holderRecord: findHolderPda(this.config, account.owner, this.programId),
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        tokenAccount: params.account,
+        blacklistEntry: findBlacklistPda(this.config, account.owner, this.programId),
+        complianceLog: findComplianceLogPda(this.config, this.programId),
+        allowlisterRole: findRolePda(this.config, Role.Allowlister, this.wallet, this.programId),
+        authority: this.wallet,
+        payer: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Toggle whether new token accounts start frozen (master admin only)
+   */
+  async setDefaultAccountState(frozen: boolean): Promise<string> {
+    const tx = await this.program.methods
+      .setDefaultAccountState(frozen)
+      .accounts({
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        authority: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Toggle automatic approval of new confidential transfer accounts (master admin only)
+   * Only for mints initialized with an auditor key
+   */
+  async configureAutoApprove(autoApproveNewAccounts: boolean): Promise<string> {
+    const tx = await this.program.methods
+      .configureAutoApprove(autoApproveNewAccounts)
+      .accounts({
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        authority: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Let a token account use confidential balances (allowlister only)
+   */
+  async approveConfidentialAccount(account: PublicKey): Promise<string> {
+    const tx = await this.program.methods
+      .approveConfidentialAccount()
+      .accounts({
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        tokenAccount: account,
+        complianceLog: findComplianceLogPda(this.config, this.programId),
+        allowlisterRole: findRolePda(this.config, Role.Allowlister, this.wallet, this.programId),
+        authority: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Change the transfer fee (fee admin only)
+   * Token-2022 applies it two epochs later
+   */
+  async updateTransferFee(params: TransferFeeParams): Promise<string> {
+    const tx = await this.program.methods
+      .updateTransferFee(params.basis_points, new web3.BN(params.maximum_fee))
+      .accounts({
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        feeAdminRole: findRolePda(this.config, Role.FeeAdmin, this.wallet, this.programId),
+        authority: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Set the token account that receives swept transfer fees (master admin only)
+   */
+  async setTreasury(treasury: PublicKey): Promise<string> {
+    const tx = await this.program.methods
+      .setTreasury()
+      .accounts({
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        treasury,
+        authority: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Harvest withheld fees from up to `MAX_BATCH_SIZE` token accounts into the
+   * mint, then withdraw the mint's withheld fees to the treasury (fee admin only)
+   */
+  async harvestFees(accounts: PublicKey[] = []): Promise<string> {
+    const tx = await this.program.methods
+      .harvestFees()
+      .accounts(await this.sweepFeesAccounts())
+      .remainingAccounts(accounts.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Withdraw withheld fees from up to `MAX_BATCH_SIZE` token accounts straight
+   * to the treasury (fee admin only)
+   */
+  async withdrawWithheldFees(accounts: PublicKey[]): Promise<string> {
+    const tx = await this.program.methods
+      .withdrawWithheldFees()
+      .accounts(await this.sweepFeesAccounts())
+      .remainingAccounts(accounts.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+      .rpc();
+
+    return tx;
+  }
+
+  private async sweepFeesAccounts() {
+    const { treasury } = await this.getConfig();
+    if (!treasury) {
+      throw new Error('No treasury: call setTreasury() first');
+    }
+    return {
+      stablecoinConfig: this.config,
+      mint: this.requireMint(),
+      treasury,
+      feeAdminRole: findRolePda(this.config, Role.FeeAdmin, this.wallet, this.programId),
+      authority: this.wallet,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+    };
+  }
+
+  /**
+   * Change the interest rate of an interest-bearing mint (rate authority only)
+   */
+  async updateRate(rate: number): Promise<string> {
+    const tx = await this.program.methods
+      .updateRate(rate)
+      .accounts({
+        rateHistory: findRateHistoryPda(this.config, this.programId),
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        rateAuthorityRole: findRolePda(this.config, Role.RateAuthority, this.wallet, this.programId),
+        authority: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Read the interest rate changes, oldest first
+   * Only the last 32 are kept
+   */
+  async getRateHistory(): Promise<RateRecord[]> {
+    const history: any = await this.program.account.rateHistory.fetch(
+      findRateHistoryPda(this.config, this.programId)
+    );
+
+    return history.records.map((record: any) => ({
+      rate: record.rate,
+      updated_by: record.updatedBy,
+      slot: BigInt(record.slot.toString()),
+      unix_timestamp: Number(record.unixTimestamp.toString()),
+    }));
+  }
+
+  /**
+   * Publish a proof-of-reserves attestation (attestor only)
+   * Minting needs a fresh attestation covering the new supply
+   */
+  async attestReserves(params: AttestReservesParams): Promise<string> {
+    const tx = await this.program.methods
+      .attestReserves(new web3.BN(params.reserves), new web3.BN(params.attested_at), params.report_hash)
+      .accounts({
+        reserveAttestation: findReservesPda(this.config, this.programId),
+        stablecoinConfig: this.config,
+        attestorRole: findRolePda(this.config, Role.Attestor, this.wallet, this.programId),
+        authority: this.wallet,
+        payer: this.wallet,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
 
-// ============================================================================
-// Interfaces
-// ============================================================================
+  /**
+   * Set how old the reserve attestation may be when minting (master admin only)
+   */
+  async setReserveStaleness(reserveStalenessSeconds: number): Promise<string> {
+    const tx = await this.program.methods
+      .setReserveStaleness(new web3.BN(reserveStalenessSeconds))
+      .accounts({
+        stablecoinConfig: this.config,
+        authority: this.wallet,
+      })
+      .rpc();
 
-export interface StablecoinConfig {
-  name: string;
-  symbol: string;
-  uri: string;
-  decimals: number;
-  enable_permanent_delegate: boolean;
-  enable_transfer_hook: boolean;
-  default_account_frozen: boolean;
-  mint_authority: PublicKey;
-  freeze_authority: PublicKey;
-}
+    return tx;
+  }
 
-export interface BlacklistEntry {
-  address: PublicKey;
-  is_blacklisted: boolean;
-  timestamp: number;
-}
+  /**
+   * Read the latest proof-of-reserves attestation
+   */
+  async getReserveAttestation(): Promise<ReserveAttestation> {
+    const attestation: any = await this.program.account.reserveAttestation.fetch(
+      findReservesPda(this.config, this.programId)
+    );
 
-export interface MintToParams {
-  to: PublicKey;
-  amount: number;
-}
+    return {
+      reserves: BigInt(attestation.reserves.toString()),
+      attested_at: Number(attestation.attestedAt.toString()),
+      report_hash: attestation.reportHash,
+      attestor: attestation.attestor,
+      slot: BigInt(attestation.slot.toString()),
+    };
+  }
 
-export interface FreezeAccountParams {
-  account: PublicKey;
-}
+  /**
+   * File a request to mint against an off-chain wire (anyone)
+   * Returns the id a minter approves or rejects
+   */
+  async requestMint(params: RequestMintParams): Promise<{ tx: string; requestId: number }> {
+    const { request_count: requestId } = await this.getConfig();
+    const tx = await this.program.methods
+      .requestMint(new web3.BN(params.amount), params.wire_reference)
+      .accounts({
+        mintRequest: findMintRequestPda(this.config, requestId, this.programId),
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        destination: params.destination,
+        requester: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
 
-export interface ModifyBlacklistParams {
-  address: PublicKey;
-}
+    return { tx, requestId };
+  }
 
-export interface SeizeTokensParams {
-  from: PublicKey;
-  treasury: PublicKey;
-  amount: number;
-}
+  /**
+   * Approve a pending mint request and mint it (minter only)
+   * Charged against the minter's quota and the attested reserves like `mintTo`
+   */
+  async approveMintRequest(requestId: number): Promise<string> {
+    const mintRequest = findMintRequestPda(this.config, requestId, this.programId);
+    const request = await this.getMintRequest(requestId);
+    const destination = await getAccount(
+      this.program.provider.connection,
+      request.account,
+      'confirmed',
+      TOKEN_2022_PROGRAM_ID
+    );
 
-export interface UpdateAuthorityParams {
-  newAuthority: PublicKey;
-}
+    const tx = await this.program.methods
+      .approveMintRequest(new web3.BN(requestId))
+      .accounts({
+        mintRequest,
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        destination: request.account,
+        allowlistEntry: findAllowlistPda(this.config, destination.owner, this.programId),
+        reserveAttestation: findReservesPda(this.config, this.programId),
+        minterInfo: findMinterPda(this.config, this.wallet, this.programId),
+        minterRole: findRolePda(this.config, Role.Minter, this.wallet, this.programId),
+        authority: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
 
-export interface InitializeWithTransferHookParams {
-  name: string;
-  symbol: string;
-  uri: string;
-  decimals: number;
-  transferHookProgram?: PublicKey; // NOVO: Transfer hook program (opcional)
-}
+    return tx;
+  }
 
-// ============================================================================
-// StablecoinSDK Class
-// ============================================================================
+  /**
+   * Reject a pending mint request (minter only)
+   */
+  async rejectMintRequest(requestId: number): Promise<string> {
+    const tx = await this.program.methods
+      .rejectMintRequest(new web3.BN(requestId))
+      .accounts({
+        mintRequest: findMintRequestPda(this.config, requestId, this.programId),
+        stablecoinConfig: this.config,
+        minterRole: findRolePda(this.config, Role.Minter, this.wallet, this.programId),
+        authority: this.wallet,
+      })
+      .rpc();
 
-export class StablecoinSDK {
-  public program: Program;
-  public programId: PublicKey;
+    return tx;
+  }
 
-  constructor(
-    provider: AnchorProvider,
-    idl: any,
-    programId: PublicKey = STABLECOIN_PROGRAM_ID
-  ) {
-    this.programId = programId;
-    this.program = new Program(idl, programId, provider);
+  /**
+   * Withdraw a pending mint request (requester only)
+   */
+  async cancelMintRequest(requestId: number): Promise<string> {
+    const tx = await this.program.methods
+      .cancelMintRequest(new web3.BN(requestId))
+      .accounts({
+        mintRequest: findMintRequestPda(this.config, requestId, this.programId),
+        stablecoinConfig: this.config,
+        requester: this.wallet,
+      })
+      .rpc();
+
+    return tx;
   }
 
   /**
-   * Initialize SSS-2 Compliant Stablecoin
-   * Creates mint, sets up authorities, and configures compliance features
+   * File a request to redeem from the wallet's own token account
+   * The tokens stay in `source` until a burner approves the request
    */
-  async initialize(config: StablecoinConfig): Promise<string> {
-    const mint = Keypair.generate();
-    const [stablecoinConfig] = await PublicKey.findProgramAddress(
-      [Buffer.from('stablecoin'), mint.publicKey.toBuffer()],
-      this.programId
-    );
+  async requestRedemption(params: RequestRedemptionParams): Promise<{ tx: string; requestId: number }> {
+    const { request_count: requestId } = await this.getConfig();
+    const tx = await this.program.methods
+      .requestRedemption(new web3.BN(params.amount), params.wire_reference)
+      .accounts({
+        redemptionRequest: findRedemptionRequestPda(this.config, requestId, this.programId),
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        source: params.source,
+        blacklistEntry: findBlacklistPda(this.config, this.wallet, this.programId),
+        allowlistEntry: findAllowlistPda(this.config, this.wallet, this.programId),
+        requester: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
 
-    const [mintAuthority] = await PublicKey.findProgramAddress(
-      [Buffer.from('authority'), Buffer.from('mint'), mint.publicKey.toBuffer()],
-      this.programId
-    );
+    return { tx, requestId };
+  }
 
-    const [freezeAuthority] = await PublicKey.findProgramAddress(
-      [Buffer.from('authority'), Buffer.from('freeze'), mint.publicKey.toBuffer()],
-      this.programId
-    );
+  /**
+   * Approve a pending redemption and burn it (burner only)
+   * Fails if the source account changed owner or the requester was
+   * blacklisted since filing
+   */
+  async approveRedemptionRequest(requestId: number): Promise<string> {
+    const request = await this.getRedemptionRequest(requestId);
+    const tx = await this.program.methods
+      .approveRedemptionRequest(new web3.BN(requestId))
+      .accounts({
+        redemptionRequest: findRedemptionRequestPda(this.config, requestId, this.programId),
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        source: request.account,
+        blacklistEntry: findBlacklistPda(this.config, request.requester, this.programId),
+        allowlistEntry: findAllowlistPda(this.config, request.requester, this.programId),
+        burnerRole: findRolePda(this.config, Role.Burner, this.wallet, this.programId),
+        authority: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    return tx;
+  }
 
+  /**
+   * Reject a pending redemption request (burner only)
+   */
+  async rejectRedemptionRequest(requestId: number): Promise<string> {
     const tx = await this.program.methods
-      .initialize(
-        config.name,
-        config.symbol,
-        config.uri,
-        config.decimals,
-        config.enable_permanent_delegate,
-        config.default_account_frozen
-      )
+      .rejectRedemptionRequest(new web3.BN(requestId))
       .accounts({
-        stablecoinConfig,
-        mint: mint.publicKey,
-        mintAuthority: config.mint_authority,
-        freezeAuthority: config.freeze_authority,
-        authority: this.program.provider.wallet.publicKey,
-        payer: this.program.provider.wallet.publicKey,
-        systemProgram: SystemProgram.programId,
-        tokenProgram: new PublicKey('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
+        redemptionRequest: findRedemptionRequestPda(this.config, requestId, this.programId),
+        stablecoinConfig: this.config,
+        burnerRole: findRolePda(this.config, Role.Burner, this.wallet, this.programId),
+        authority: this.wallet,
       })
-      .signers([mint])
       .rpc();
 
-    console.log('Stablecoin initialized:', tx);
     return tx;
   }
 
   /**
-   * Initialize stablecoin WITH transfer hook (NOVO)
-   * Allows specifying a separate transfer hook program for blacklist enforcement
+   * Withdraw a pending redemption request (requester only)
    */
-  async initializeWithTransferHook(
-    config: StablecoinConfig & {
-      transferHookProgram?: PublicKey
-    }
-  ): Promise<string> {
-    const mint = Keypair.generate();
-    const [stablecoinConfig] = await PublicKey.findProgramAddress(
-      [Buffer.from('stablecoin'), mint.publicKey.toBuffer()],
-      this.programId
-    );
+  async cancelRedemptionRequest(requestId: number): Promise<string> {
+    const tx = await this.program.methods
+      .cancelRedemptionRequest(new web3.BN(requestId))
+      .accounts({
+        redemptionRequest: findRedemptionRequestPda(this.config, requestId, this.programId),
+        stablecoinConfig: this.config,
+        requester: this.wallet,
+      })
+      .rpc();
+
+    return tx;
+  }
 
-    const [mintAuthority] = await PublicKey.findProgramAddress(
-      [Buffer.from('authority'), Buffer.from('mint'), mint.publicKey.toBuffer()],
-      this.programId
+  /**
+   * Read a mint request; `account` is its destination
+   */
+  async getMintRequest(requestId: number): Promise<FiatRequest> {
+    const request: any = await this.program.account.mintRequest.fetch(
+      findMintRequestPda(this.config, requestId, this.programId)
     );
 
-    const [freezeAuthority] = await PublicKey.findProgramAddress(
-      [Buffer.from('authority'), Buffer.from('freeze'), mint.publicKey.toBuffer()],
-      this.programId
+    return fiatRequestFromAccount(request, request.destination);
+  }
+
+  /**
+   * Read a redemption request; `account` is its source
+   */
+  async getRedemptionRequest(requestId: number): Promise<FiatRequest> {
+    const request: any = await this.program.account.redemptionRequest.fetch(
+      findRedemptionRequestPda(this.config, requestId, this.programId)
     );
 
+    return fiatRequestFromAccount(request, request.source);
+  }
+
+  /**
+   * Switch between open, blacklist and allowlist enforcement (master admin only)
+   */
+  async setComplianceMode(mode: ComplianceMode): Promise<string> {
     const tx = await this.program.methods
-      .initialize(
-        config.name,
-        config.symbol,
-        config.uri,
-        config.decimals,
-        config.enable_permanent_delegate,
-        config.default_account_frozen,
-        config.transferHookProgram || null // NOVO: Hook program opcional
-      )
+      .setComplianceMode(complianceModeArg(mode))
       .accounts({
-        stablecoinConfig,
-        mint: mint.publicKey,
-        mintAuthority: config.mint_authority,
-        freezeAuthority: config.freeze_authority,
-        authority: this.program.provider.wallet.publicKey,
-        payer: this.program.provider.wallet.publicKey,
-        systemProgram: SystemProgram.programId,
-        tokenProgram: new PublicKey('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
+        stablecoinConfig: this.config,
+        authority: this.wallet,
       })
-      .signers([mint])
       .rpc();
 
-    console.log('Stablecoin initialized with hook:', config.transferHookProgram);
     return tx;
   }
 
   /**
-   * Mint new tokens to a recipient account
-   * Only callable by mint authority
+   * Set the account that receives rent from closed blacklist entries (master admin only)
    */
-  async mintTo(params: MintToParams): Promise<string> {
-    const [mintAuthority] = await PublicKey.findProgramAddress(
-      [Buffer.from('authority'), Buffer.from('mint'), params.to.toBuffer()],
-      this.programId
+  async setRentCollector(rentCollector: PublicKey): Promise<string> {
+    const tx = await this.program.methods
+      .setRentCollector(rentCollector)
+      .accounts({
+        stablecoinConfig: this.config,
+        authority: this.wallet,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+
+  /**
+   * Seize tokens from a blacklisted account (seizer only)
+   * Moves them to the treasury through the PermanentDelegate and leaves the
+   * source frozen; the transfer hook accounts are resolved here
+   */
+  async seizeTokens(params: SeizeTokensParams): Promise<string> {
+    const connection = this.program.provider.connection;
+    const mint = this.requireMint();
+    const from = await getAccount(connection, params.from, 'confirmed', TOKEN_2022_PROGRAM_ID);
+    const config = await this.program.account.stablecoinConfig.fetch(this.config);
+
+    // source, mint, destination and authority come first; the rest are the
+    // hook's extra accounts
+    const transfer = await createTransferCheckedWithTransferHookInstruction(
+      connection,
+      params.from,
+      mint,
+      params.treasury,
+      this.config,
+      BigInt(params.amount),
+      config.decimals as number,
+      [],
+      'confirmed',
+      TOKEN_2022_PROGRAM_ID
     );
 
     const tx = await this.program.methods
-      .mintTo(new web3.BN(params.amount))
+      .seizeTokens(new web3.BN(params.amount), params.reason_code ?? null, params.case_reference ?? null)
       .accounts({
-        mint: params.to,
-        mintAuthority,
-        to: params.to,
-        payer: this.program.provider.wallet.publicKey,
-        tokenProgram: new PublicKey('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
+        stablecoinConfig: this.config,
+        mint,
+        from: params.from,
+        blacklistEntry: findBlacklistPda(this.config, from.owner, this.programId),
+        treasury: params.treasury,
+        complianceLog: findComplianceLogPda(this.config, this.programId),
+        seizerRole: findRolePda(this.config, Role.Seizer, this.wallet, this.programId),
+        authority: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
       })
+      .remainingAccounts(transfer.keys.slice(4))
       .rpc();
 
     return tx;
   }
 
   /**
-   * Freeze a token account (compliance)
-   * Uses Token-2022 Freeze extension
+   * Nominate a new master admin (multi-sig compatible)
+   * Nothing changes until the nominee calls `acceptAuthority`
    */
-  async freezeAccount(params: FreezeAccountParams): Promise<string> {
-    const [freezeAuthority] = await PublicKey.findProgramAddress(
-      [Buffer.from('authority'), Buffer.from('freeze'), params.account.toBuffer()],
-      this.programId
-    );
+  async proposeAuthority(params: UpdateAuthorityParams): Promise<string> {
+    const tx = await this.program.methods
+      .proposeAuthority(params.newAuthority)
+      .accounts({
+        stablecoinConfig: this.config,
+        authority: this.wallet,
+      })
+      .rpc();
+
+    return tx;
+  }
 
+  /**
+   * Accept a pending master admin nomination (signed by the nominee)
+   */
+  async acceptAuthority(newAuthority: Keypair): Promise<string> {
     const tx = await this.program.methods
-      .freezeAccount()
+      .acceptAuthority()
       .accounts({
-        mint: params.account,
-        tokenAccount: params.account,
-        freezeAuthority,
-        tokenProgram: new PublicKey('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
+        stablecoinConfig: this.config,
+        newAuthority: newAuthority.publicKey,
       })
+      .signers([newAuthority])
       .rpc();
 
     return tx;
   }
 
   /**
-   * Thaw a frozen token account
-   * Uses Token-2022 Thaw extension
+   * Withdraw a pending nomination (master admin only)
    */
-  async thawAccount(params: FreezeAccountParams): Promise<string> {
-    const [freezeAuthority] = await PublicKey.findProgramAddress(
-      [Buffer.from('authority'), Buffer.from('freeze'), params.account.toBuffer()],
-      this.programId
-    );
+  async cancelAuthorityTransfer(): Promise<string> {
+    const tx = await this.program.methods
+      .cancelAuthorityTransfer()
+      .accounts({
+        stablecoinConfig: this.config,
+        authority: this.wallet,
+      })
+      .rpc();
+
+    return tx;
+  }
 
+  /**
+   * Turn on the governance timelock (master admin only)
+   * Afterwards role grants, quota increases, unpausing and config changes are
+   * queued with `proposeAction`; changing the delay is itself a proposal
+   */
+  async setTimelock(timelockSeconds: number): Promise<string> {
     const tx = await this.program.methods
-      .thawAccount()
+      .setTimelock(new web3.BN(timelockSeconds))
       .accounts({
-        mint: params.account,
-        tokenAccount: params.account,
-        freezeAuthority,
-        tokenProgram: new PublicKey('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
+        stablecoinConfig: this.config,
+        authority: this.wallet,
       })
       .rpc();
 
@@ -255,21 +1506,50 @@ export class StablecoinSDK {
   }
 
   /**
-   * Add an address to blacklist (compliance)
-   * Stores blacklist status in PDA for Transfer Hook
+   * Queue a sensitive operation (master admin only)
+   * Returns the id to pass to `executeAction` once the timelock has passed
    */
-  async addToBlacklist(params: ModifyBlacklistParams): Promise<string> {
-    const [blacklistEntry] = await PublicKey.findProgramAddress(
-      [Buffer.from('blacklist'), params.address.toBuffer()],
-      this.programId
-    );
+  async proposeAction(action: GovernanceAction): Promise<{ tx: string; actionId: number }> {
+    const { proposal_count: actionId } = await this.getConfig();
+    const tx = await this.program.methods
+      .proposeAction(governanceActionArg(action))
+      .accounts({
+        proposedAction: findProposalPda(this.config, actionId, this.programId),
+        stablecoinConfig: this.config,
+        authority: this.wallet,
+        payer: this.wallet,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return { tx, actionId };
+  }
+
+  /**
+   * Apply a queued action after its eta (the master admin who proposed it)
+   * The accounts an action needs are derived from the stored proposal
+   */
+  async executeAction(actionId: number): Promise<string> {
+    const proposedAction = findProposalPda(this.config, actionId, this.programId);
+    const proposal: any = await this.program.account.proposedAction.fetch(proposedAction);
+    const { configureMinter, grantRole, setTreasury } = proposal.action;
 
     const tx = await this.program.methods
-      .addToBlacklist(params.address)
+      .executeAction(new web3.BN(actionId))
       .accounts({
-        blacklistEntry,
-        authority: this.program.provider.wallet.publicKey,
-        payer: this.program.provider.wallet.publicKey,
+        proposedAction,
+        stablecoinConfig: this.config,
+        mint: this.requireMint(),
+        minterInfo: configureMinter
+          ? findMinterPda(this.config, configureMinter.minter, this.programId)
+          : null,
+        roleGrant: grantRole
+          ? findRolePda(this.config, roleFromArg(grantRole.role), grantRole.holder, this.programId)
+          : null,
+        treasury: setTreasury ? setTreasury.treasury : null,
+        payer: proposal.payer,
+        authority: this.wallet,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -278,20 +1558,39 @@ export class StablecoinSDK {
   }
 
   /**
-   * Remove an address from blacklist
+   * Drop a queued action before it executes (guardian only)
    */
-  async removeFromBlacklist(params: ModifyBlacklistParams): Promise<string> {
-    const [blacklistEntry] = await PublicKey.findProgramAddress(
-      [Buffer.from('blacklist'), params.address.toBuffer()],
-      this.programId
-    );
+  async cancelAction(actionId: number): Promise<string> {
+    const proposedAction = findProposalPda(this.config, actionId, this.programId);
+    const proposal: any = await this.program.account.proposedAction.fetch(proposedAction);
 
     const tx = await this.program.methods
-      .removeFromBlacklist(params.address)
+      .cancelAction(new web3.BN(actionId))
+      .accounts({
+        proposedAction,
+        stablecoinConfig: this.config,
+        payer: proposal.payer,
+        guardianRole: findRolePda(this.config, Role.Guardian, this.wallet, this.programId),
+        authority: this.wallet,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Create the built-in M-of-N admin multisig (master admin only)
+   * Its PDA (`findMultisigPda`) can then be granted roles or nominated as
+   * master admin
+   */
+  async createMultisig(signers: PublicKey[], threshold: number): Promise<string> {
+    const tx = await this.program.methods
+      .createMultisig(signers, threshold)
       .accounts({
-        blacklistEntry,
-        authority: this.program.provider.wallet.publicKey,
-        payer: this.program.provider.wallet.publicKey,
+        multisig: findMultisigPda(this.config, this.programId),
+        stablecoinConfig: this.config,
+        authority: this.wallet,
+        payer: this.wallet,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -300,38 +1599,48 @@ export class StablecoinSDK {
   }
 
   /**
-   * Seize tokens from a blacklisted account (compliance)
-   * Freeze + Transfer to treasury
+   * Propose a call to this program, signed by the multisig PDA once executed
+   * (multisig signers only; counts as the first approval)
+   * Returns the nonce to approve and execute
    */
-  async seizeTokens(params: SeizeTokensParams): Promise<string> {
-    const [freezeAuthority] = await PublicKey.findProgramAddress(
-      [Buffer.from('authority'), Buffer.from('freeze'), params.from.toBuffer()],
-      this.programId
-    );
+  async createProposal(instruction: TransactionInstruction): Promise<{ tx: string; nonce: number }> {
+    const multisig = findMultisigPda(this.config, this.programId);
+    const account: any = await this.program.account.adminMultisig.fetch(multisig);
+    const nonce = account.nonce.toNumber();
 
     const tx = await this.program.methods
-      .seizeTokens(new web3.BN(params.amount))
+      .createProposal(
+        instruction.keys.map((key) => ({
+          pubkey: key.pubkey,
+          isSigner: key.isSigner,
+          isWritable: key.isWritable,
+        })),
+        instruction.data
+      )
       .accounts({
-        mint: params.from,
-        from: params.from,
-        fromAuthority: this.program.provider.wallet.publicKey,
-        treasury: params.treasury,
-        freezeAuthority,
-        tokenProgram: new PublicKey('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'),
+        proposal: findMultisigProposalPda(multisig, nonce, this.programId),
+        multisig,
+        stablecoinConfig: this.config,
+        proposer: this.wallet,
+        systemProgram: SystemProgram.programId,
       })
       .rpc();
 
-    return tx;
+    return { tx, nonce };
   }
 
   /**
-   * Update mint authority (multi-sig compatible)
+   * Approve a pending multisig proposal (multisig signers only)
    */
-  async updateMinter(params: UpdateAuthorityParams): Promise<string> {
+  async approveProposal(nonce: number): Promise<string> {
+    const multisig = findMultisigPda(this.config, this.programId);
     const tx = await this.program.methods
-      .updateMinter(params.newAuthority)
+      .approveProposal(new web3.BN(nonce))
       .accounts({
-        authority: this.program.provider.wallet.publicKey,
+        proposal: findMultisigProposalPda(multisig, nonce, this.programId),
+        multisig,
+        stablecoinConfig: this.config,
+        signer: this.wallet,
       })
       .rpc();
 
@@ -339,14 +1648,32 @@ export class StablecoinSDK {
   }
 
   /**
-   * Update freeze authority (multi-sig compatible)
+   * Execute a proposal that reached the threshold (multisig signers only)
+   * The accounts of the proposed instruction are read back from the proposal
    */
-  async updateFreezer(params: UpdateAuthorityParams): Promise<string> {
+  async executeProposal(nonce: number): Promise<string> {
+    const multisig = findMultisigPda(this.config, this.programId);
+    const proposal = findMultisigProposalPda(multisig, nonce, this.programId);
+    const account: any = await this.program.account.multisigProposal.fetch(proposal);
+
+    // The multisig PDA is signed for by the program, not the transaction
+    const remainingAccounts = account.accounts.map((meta: any) => ({
+      pubkey: meta.pubkey,
+      isSigner: meta.isSigner && !meta.pubkey.equals(multisig),
+      isWritable: meta.isWritable,
+    }));
+    remainingAccounts.push({ pubkey: this.programId, isSigner: false, isWritable: false });
+
     const tx = await this.program.methods
-      .updateFreezer(params.newAuthority)
+      .executeProposal(new web3.BN(nonce))
       .accounts({
-        authority: this.program.provider.wallet.publicKey,
+        proposal,
+        multisig,
+        stablecoinConfig: this.config,
+        proposer: account.proposer,
+        signer: this.wallet,
       })
+      .remainingAccounts(remainingAccounts)
       .rpc();
 
     return tx;
@@ -357,41 +1684,71 @@ export class StablecoinSDK {
    * Returns true if blacklisted, false otherwise
    */
   async isBlacklisted(address: PublicKey): Promise<boolean> {
-    const [blacklistEntry] = await PublicKey.findProgramAddress(
-      [Buffer.from('blacklist'), address.toBuffer()],
-      this.programId
-    );
+    const blacklistEntry = findBlacklistPda(this.config, address, this.programId);
 
     try {
       const account = await this.program.account.blacklistEntry.fetch(blacklistEntry);
-      return account.isBlacklisted;
+      return account.isBlacklisted as boolean;
     } catch (error) {
       // Account doesn't exist = not blacklisted
       return false;
     }
   }
-
   /**
-   * Get stablecoin configuration
+   * Read the on-chain compliance audit trail, oldest record first
+   * Only the most recent records are kept once the ring buffer wraps
    */
-  async getConfig(mint: PublicKey): Promise<StablecoinConfig> {
-    const [stablecoinConfig] = await PublicKey.findProgramAddress(
-      [Buffer.from('stablecoin'), mint.toBuffer()],
-      this.programId
+  async getComplianceLog(): Promise<ComplianceRecord[]> {
+    const log: any = await this.program.account.complianceLog.fetch(
+      findComplianceLogPda(this.config, this.programId)
     );
+    const total = Number(log.totalRecords.toString());
+    const capacity = log.records.length;
+    const count = Math.min(total, capacity);
+
+    const records: ComplianceRecord[] = [];
+    for (let i = total - count; i < total; i++) {
+      const record = log.records[i % capacity];
+      records.push({
+        action: record.action as ComplianceAction,
+        actor: record.actor,
+        target: record.target,
+        amount: BigInt(record.amount.toString()),
+        reason_code: record.reasonCode,
+        case_reference: record.caseReference,
+        slot: BigInt(record.slot.toString()),
+        unix_timestamp: Number(record.unixTimestamp.toString()),
+      });
+    }
+    return records;
+  }
 
-    const account = await this.program.account.stablecoinConfig.fetch(stablecoinConfig);
+
+  /**
+   * Get stablecoin configuration
+   */
+  async getConfig(mint: PublicKey = this.requireMint()): Promise<StablecoinConfig> {
+    const stablecoinConfig = findConfigPda(mint, this.programId);
+    const account: any = await this.program.account.stablecoinConfig.fetch(stablecoinConfig);
 
     return {
       name: account.name,
       symbol: account.symbol,
       uri: account.uri,
       decimals: account.decimals,
-      enable_permanent_delegate: account.enablePermanentDelegate,
-      enable_transfer_hook: account.enableTransferHook,
-      default_account_frozen: account.defaultAccountFrozen,
-      mint_authority: new PublicKey(account.mintAuthority),
-      freeze_authority: new PublicKey(account.freezeAuthority),
+      master_admin: account.masterAdmin,
+      pending_master_admin: account.pendingMasterAdmin,
+      paused: account.paused,
+      compliance_mode: Object.keys(account.complianceMode)[0] as ComplianceMode,
+      rent_collector: account.rentCollector,
+      timelock_seconds: account.timelockSeconds.toNumber(),
+      proposal_count: account.proposalCount.toNumber(),
+      treasury: account.treasury,
+      reserve_staleness_seconds: account.reserveStalenessSeconds.toNumber(),
+      request_count: account.requestCount.toNumber(),
+      total_minted: BigInt(account.totalMinted.toString()),
+      total_burned: BigInt(account.totalBurned.toString()),
+      circulating_supply: BigInt(account.circulatingSupply.toString()),
     };
   }
 }
@@ -406,7 +1763,8 @@ export class StablecoinSDK {
 export function createStablecoinSDK(
   connection: Connection,
   wallet: any,
-  programId?: PublicKey
+  programId?: PublicKey,
+  mint?: PublicKey
 ): StablecoinSDK {
   const provider = new AnchorProvider(connection, wallet, {
     commitment: 'confirmed',
@@ -423,7 +1781,7 @@ export function createStablecoinSDK(
     types: [],
   };
 
-  return new StablecoinSDK(provider, idl, programId);
+  return new StablecoinSDK(provider, idl, programId, mint);
 }
 
 /**
@@ -435,57 +1793,34 @@ export async function exampleInitialize(): Promise<void> {
 
   const sdk = createStablecoinSDK(connection, wallet);
 
-  const config: StablecoinConfig = {
+  const params: InitializeParams = {
     name: 'My Stablecoin',
     symbol: 'MSTBL',
     uri: 'https://example.com/metadata.json',
     decimals: 9,
-    enable_permanent_delegate: true,
-    enable_transfer_hook: true,
+    compliance_mode: 'blacklist',
     default_account_frozen: false,
-    mint_authority: wallet.publicKey,
-    freeze_authority: wallet.publicKey,
+    reserve_staleness_seconds: 24 * 60 * 60,
   };
 
-  const tx = await sdk.initialize(config);
+  const tx = await sdk.initialize(params);
   console.log('Stablecoin initialized:', tx);
-}
-
-/**
- * Example: Initialize stablecoin WITH transfer hook (NOVO)
- */
-export async function exampleInitializeWithTransferHook(): Promise<void> {
-  const connection = new Connection('https://api.devnet.solana.com');
-  const wallet = window.solana; // Phantom wallet
-  const hookProgramId = new PublicKey('hookProg11111111111111111111'); // Simulado para teste
-
-  const sdk = createStablecoinSDK(connection, wallet);
-
-  const config: StablecoinConfig = {
-    name: 'My Stablecoin',
-    symbol: 'MSTBL',
-    uri: 'https://example.com/metadata.json',
-    decimals: 9,
-    enable_permanent_delegate: true,
-    enable_transfer_hook: true,
-    default_account_frozen: false,
-    mint_authority: wallet.publicKey,
-    freeze_authority: wallet.publicKey,
-    transferHookProgram: hookProgramId, // NOVO: Especificar hook program
-  };
 
-  const tx = await sdk.initializeWithTransferHook(config);
-  console.log('Stablecoin initialized with hook:', tx);
+  // The master admin hands out the operational roles
+  await sdk.grantRole({ role: Role.MasterMinter, holder: wallet.publicKey });
+  await sdk.grantRole({ role: Role.Minter, holder: wallet.publicKey });
+  await sdk.configureMinter({ minter: wallet.publicKey, allowance: 1_000_000_000_000, daily_cap: 0 });
+  await sdk.grantRole({ role: Role.Blacklister, holder: wallet.publicKey });
 }
 
 /**
  * Example: Mint tokens to a recipient
  */
-export async function exampleMintTo(to: PublicKey, amount: number): Promise<void> {
+export async function exampleMintTo(mint: PublicKey, to: PublicKey, amount: number): Promise<void> {
   const connection = new Connection('https://api.devnet.solana.com');
   const wallet = window.solana;
 
-  const sdk = createStablecoinSDK(connection, wallet);
+  const sdk = createStablecoinSDK(connection, wallet, undefined, mint);
 
   const tx = await sdk.mintTo({ to, amount });
   console.log('Tokens minted:', tx);
@@ -494,11 +1829,11 @@ export async function exampleMintTo(to: PublicKey, amount: number): Promise<void
 /**
  * Example: Freeze an account
  */
-export async function exampleFreezeAccount(account: PublicKey): Promise<void> {
+export async function exampleFreezeAccount(mint: PublicKey, account: PublicKey): Promise<void> {
   const connection = new Connection('https://api.devnet.solana.com');
   const wallet = window.solana;
 
-  const sdk = createStablecoinSDK(connection, wallet);
+  const sdk = createStablecoinSDK(connection, wallet, undefined, mint);
 
   const tx = await sdk.freezeAccount({ account });
   console.log('Account frozen:', tx);
@@ -507,11 +1842,11 @@ export async function exampleFreezeAccount(account: PublicKey): Promise<void> {
 /**
  * Example: Add address to blacklist
  */
-export async function exampleAddToBlacklist(address: PublicKey): Promise<void> {
+export async function exampleAddToBlacklist(mint: PublicKey, address: PublicKey): Promise<void> {
   const connection = new Connection('https://api.devnet.solana.com');
   const wallet = window.solana;
 
-  const sdk = createStablecoinSDK(connection, wallet);
+  const sdk = createStablecoinSDK(connection, wallet, undefined, mint);
 
   const tx = await sdk.addToBlacklist({ address });
   console.log('Address blacklisted:', tx);
@@ -521,6 +1856,7 @@ export async function exampleAddToBlacklist(address: PublicKey): Promise<void> {
  * Example: Seize tokens from a blacklisted account
  */
 export async function exampleSeizeTokens(
+  mint: PublicKey,
   from: PublicKey,
   treasury: PublicKey,
   amount: number
@@ -528,7 +1864,7 @@ export async function exampleSeizeTokens(
   const connection = new Connection('https://api.devnet.solana.com');
   const wallet = window.solana;
 
-  const sdk = createStablecoinSDK(connection, wallet);
+  const sdk = createStablecoinSDK(connection, wallet, undefined, mint);
 
   const tx = await sdk.seizeTokens({ from, treasury, amount });
   console.log('Tokens seized:', tx);
@@ -537,11 +1873,11 @@ export async function exampleSeizeTokens(
 /**
  * Example: Check if address is blacklisted
  */
-export async function exampleIsBlacklisted(address: PublicKey): Promise<boolean> {
+export async function exampleIsBlacklisted(mint: PublicKey, address: PublicKey): Promise<boolean> {
   const connection = new Connection('https://api.devnet.solana.com');
   const wallet = window.solana;
 
-  const sdk = createStablecoinSDK(connection, wallet);
+  const sdk = createStablecoinSDK(connection, wallet, undefined, mint);
 
   const isBlacklisted = await sdk.isBlacklisted(address);
   console.log('Is blacklisted:', isBlacklisted);
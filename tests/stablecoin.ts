@@ -3,19 +3,58 @@
 
 import * as anchor from '@project-serum/anchor';
 import { Program } from '@project-serum/anchor';
-import { PublicKey, Keypair, SystemProgram, LAMPORTS_PER_SOL } from '@solana/web3.js';
+import { PublicKey, Keypair, SystemProgram } from '@solana/web3.js';
 import {
-  TOKEN_PROGRAM_ID,
-  createMint,
+  AuthorityType,
+  TOKEN_2022_PROGRAM_ID,
+  approve,
   createAccount,
-  mintTo,
+  createTransferCheckedWithTransferHookInstruction,
   getAccount,
+  getMint,
+  getTokenMetadata,
+  setAuthority,
+  transferCheckedWithTransferHook,
 } from '@solana/spl-token';
 import { assert } from 'chai';
 
 // Load the program IDL
 const IDL = require('../target/idl/solana_stablecoin_standard.json');
 
+// On-chain `Role` order, used in the role PDA seeds
+const Role = {
+  Minter: 0,
+  Burner: 1,
+  Pauser: 2,
+  Blacklister: 3,
+  Seizer: 4,
+  MasterMinter: 5,
+  MetadataAdmin: 6,
+  Allowlister: 7,
+  Guardian: 8,
+  FeeAdmin: 9,
+  RateAuthority: 10,
+  Attestor: 11,
+};
+
+const roleArgs: Record<number, object> = {
+  [Role.Minter]: { minter: {} },
+  [Role.Burner]: { burner: {} },
+  [Role.Pauser]: { pauser: {} },
+  [Role.Blacklister]: { blacklister: {} },
+  [Role.Seizer]: { seizer: {} },
+  [Role.MasterMinter]: { masterMinter: {} },
+  [Role.MetadataAdmin]: { metadataAdmin: {} },
+  [Role.Allowlister]: { allowlister: {} },
+  [Role.Guardian]: { guardian: {} },
+  [Role.FeeAdmin]: { feeAdmin: {} },
+  [Role.RateAuthority]: { rateAuthority: {} },
+  [Role.Attestor]: { attestor: {} },
+};
+
+// Max age of the reserve attestation backing mints
+const RESERVE_STALENESS_SECONDS = new anchor.BN(24 * 60 * 60);
+
 describe('Solana Stablecoin Standard - Integration Tests', () => {
   // Configure the client to use the local cluster
   const provider = anchor.AnchorProvider.env();
@@ -25,21 +64,407 @@ describe('Solana Stablecoin Standard - Integration Tests', () => {
 
   let mint: Keypair;
   let stablecoinConfig: PublicKey;
-  let mintAuthority: PublicKey;
-  let freezeAuthority: PublicKey;
   let blacklistEntry: PublicKey;
 
+  // Owned by the authority
   let userTokenAccount: PublicKey;
+  // Counterparty of the transfer hook tests
+  const holder = Keypair.generate();
+  let holderTokenAccount: PublicKey;
   let treasuryAccount: PublicKey;
 
   const authority = provider.wallet as anchor.Wallet;
 
   console.log('Testing with authority:', authority.publicKey.toString());
 
+  const rolePda = (role: number, roleHolder: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('role'), stablecoinConfig.toBuffer(), Buffer.from([role]), roleHolder.toBuffer()],
+      program.programId
+    )[0];
+
+  const grantRole = (role: number, roleHolder: PublicKey) =>
+    program.methods
+      .grantRole(roleArgs[role], roleHolder)
+      .accounts({
+        roleGrant: rolePda(role, roleHolder),
+        stablecoinConfig,
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+  const complianceLogPda = () =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('compliance_log'), stablecoinConfig.toBuffer()],
+      program.programId
+    )[0];
+
+  // Most recent compliance record, `action` is the `ComplianceAction` index
+  const lastComplianceRecord = async () => {
+    const log = await program.account.complianceLog.fetch(complianceLogPda());
+    const total = Number(log.totalRecords.toString());
+    return log.records[(total - 1) % log.records.length];
+  };
+
+  const minterPda = (minter: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('minter'), stablecoinConfig.toBuffer(), minter.toBuffer()],
+      program.programId
+    )[0];
+
+  const allowlistPda = (address: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('allowlist'), stablecoinConfig.toBuffer(), address.toBuffer()],
+      program.programId
+    )[0];
+
+  const reservesPda = () =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('reserves'), stablecoinConfig.toBuffer()],
+      program.programId
+    )[0];
+
+  const attestReserves = (reserves: anchor.BN, attestedAt: number) =>
+    program.methods
+      .attestReserves(reserves, new anchor.BN(attestedAt), Array(32).fill(7))
+      .accounts({
+        reserveAttestation: reservesPda(),
+        stablecoinConfig,
+        attestorRole: rolePda(Role.Attestor, authority.publicKey),
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+  const setReserveStaleness = (seconds: number) =>
+    program.methods
+      .setReserveStaleness(new anchor.BN(seconds))
+      .accounts({ stablecoinConfig, authority: authority.publicKey })
+      .rpc();
+
+  const requestPda = (seed: string, requestId: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from(seed), stablecoinConfig.toBuffer(), new anchor.BN(requestId).toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    )[0];
+
+  const nextRequestId = async () =>
+    (await program.account.stablecoinConfig.fetch(stablecoinConfig)).requestCount.toNumber();
+
+  const requestMint = async (destination: PublicKey, amount: number) => {
+    const requestId = await nextRequestId();
+    await program.methods
+      .requestMint(new anchor.BN(amount), Array(32).fill(1))
+      .accounts({
+        mintRequest: requestPda('mint_request', requestId),
+        stablecoinConfig,
+        mint: mint.publicKey,
+        destination,
+        requester: authority.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    return requestId;
+  };
+
+  // Destinations in these tests are owned by the authority
+  const approveMintRequest = (requestId: number, destination: PublicKey) =>
+    program.methods
+      .approveMintRequest(new anchor.BN(requestId))
+      .accounts({
+        mintRequest: requestPda('mint_request', requestId),
+        stablecoinConfig,
+        mint: mint.publicKey,
+        destination,
+        allowlistEntry: allowlistPda(authority.publicKey),
+        reserveAttestation: reservesPda(),
+        minterInfo: minterPda(authority.publicKey),
+        minterRole: rolePda(Role.Minter, authority.publicKey),
+        authority: authority.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+  const requestRedemption = async (source: PublicKey, amount: number) => {
+    const requestId = await nextRequestId();
+    await program.methods
+      .requestRedemption(new anchor.BN(amount), Array(32).fill(2))
+      .accounts({
+        redemptionRequest: requestPda('redemption_request', requestId),
+        stablecoinConfig,
+        mint: mint.publicKey,
+        source,
+        blacklistEntry: blacklistPda(authority.publicKey),
+        allowlistEntry: allowlistPda(authority.publicKey),
+        requester: authority.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    return requestId;
+  };
+
+  const approveRedemptionRequest = (requestId: number, source: PublicKey) =>
+    program.methods
+      .approveRedemptionRequest(new anchor.BN(requestId))
+      .accounts({
+        redemptionRequest: requestPda('redemption_request', requestId),
+        stablecoinConfig,
+        mint: mint.publicKey,
+        source,
+        blacklistEntry: blacklistPda(authority.publicKey),
+        allowlistEntry: allowlistPda(authority.publicKey),
+        burnerRole: rolePda(Role.Burner, authority.publicKey),
+        authority: authority.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+  const modifyAllowlistAccounts = (address: PublicKey) => ({
+    allowlistEntry: allowlistPda(address),
+    stablecoinConfig,
+    mint: mint.publicKey,
+    complianceLog: complianceLogPda(),
+    allowlisterRole: rolePda(Role.Allowlister, authority.publicKey),
+    authority: authority.publicKey,
+    payer: authority.publicKey,
+    systemProgram: SystemProgram.programId,
+  });
+
+  const writable = (pubkey: PublicKey) => ({ pubkey, isSigner: false, isWritable: true });
+
+  const batchBlacklistAccounts = () => ({
+    stablecoinConfig,
+    mint: mint.publicKey,
+    complianceLog: complianceLogPda(),
+    blacklisterRole: rolePda(Role.Blacklister, authority.publicKey),
+    authority: authority.publicKey,
+    payer: authority.publicKey,
+    systemProgram: SystemProgram.programId,
+  });
+
+  const batchFreeze = (targets: PublicKey[], accounts: PublicKey[]) =>
+    program.methods
+      .batchFreeze(targets, null, null)
+      .accounts({
+        stablecoinConfig,
+        mint: mint.publicKey,
+        complianceLog: complianceLogPda(),
+        blacklisterRole: rolePda(Role.Blacklister, authority.publicKey),
+        authority: authority.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(accounts.map(writable))
+      .rpc();
+
+  const approveHolder = (tokenAccount: PublicKey, owner: PublicKey, expiry: number) =>
+    program.methods
+      .approveHolder(1, Array.from(Buffer.from('US')), new anchor.BN(expiry))
+      .accounts({
+        holderRecord: PublicKey.findProgramAddressSync(
+          [Buffer.from('holder'), stablecoinConfig.toBuffer(), owner.toBuffer()],
+          program.programId
+        )[0],
+        stablecoinConfig,
+        mint: mint.publicKey,
+        tokenAccount,
+        blacklistEntry: blacklistPda(owner),
+        complianceLog: complianceLogPda(),
+        allowlisterRole: rolePda(Role.Allowlister, authority.publicKey),
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+  const setDefaultAccountState = (frozen: boolean) =>
+    program.methods
+      .setDefaultAccountState(frozen)
+      .accounts({
+        stablecoinConfig,
+        mint: mint.publicKey,
+        authority: authority.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+  const setComplianceMode = (mode: object) =>
+    program.methods
+      .setComplianceMode(mode)
+      .accounts({
+        stablecoinConfig,
+        authority: authority.publicKey,
+      })
+      .rpc();
+
+  const mintAccounts = (to: PublicKey, owner = authority.publicKey) => ({
+    stablecoinConfig,
+    mint: mint.publicKey,
+    to,
+    allowlistEntry: allowlistPda(owner),
+    reserveAttestation: reservesPda(),
+    minterInfo: minterPda(authority.publicKey),
+    minterRole: rolePda(Role.Minter, authority.publicKey),
+    authority: authority.publicKey,
+    tokenProgram: TOKEN_2022_PROGRAM_ID,
+  });
+
+  const burnAccounts = (from: PublicKey) => ({
+    stablecoinConfig,
+    mint: mint.publicKey,
+    from,
+    tokenProgram: TOKEN_2022_PROGRAM_ID,
+  });
+
+  // The config PDA moves the funds as permanent delegate; the hook's extra
+  // accounts go in remaining_accounts
+  const seize = async (from: PublicKey, owner: PublicKey, amount: number) => {
+    const hookTransfer = await createTransferCheckedWithTransferHookInstruction(
+      provider.connection,
+      from,
+      mint.publicKey,
+      treasuryAccount,
+      stablecoinConfig,
+      BigInt(amount),
+      9,
+      [],
+      'confirmed',
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    return program.methods
+      .seizeTokens(new anchor.BN(amount), 2, null)
+      .accounts({
+        stablecoinConfig,
+        mint: mint.publicKey,
+        from,
+        blacklistEntry: blacklistPda(owner),
+        treasury: treasuryAccount,
+        complianceLog: complianceLogPda(),
+        seizerRole: rolePda(Role.Seizer, authority.publicKey),
+        authority: authority.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .remainingAccounts(hookTransfer.keys.slice(4))
+      .rpc();
+  };
+
+  const setPausedAccounts = () => ({
+    stablecoinConfig,
+    pauserRole: rolePda(Role.Pauser, authority.publicKey),
+    authority: authority.publicKey,
+  });
+
+  const blacklistPda = (address: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('blacklist'), stablecoinConfig.toBuffer(), address.toBuffer()],
+      program.programId
+    )[0];
+
+  const removeBlacklistAccounts = (address: PublicKey) => ({
+    blacklistEntry: blacklistPda(address),
+    stablecoinConfig,
+    mint: mint.publicKey,
+    complianceLog: complianceLogPda(),
+    blacklisterRole: rolePda(Role.Blacklister, authority.publicKey),
+    authority: authority.publicKey,
+  });
+
+  const modifyBlacklistAccounts = (address: PublicKey) => ({
+    ...removeBlacklistAccounts(address),
+    payer: authority.publicKey,
+    systemProgram: SystemProgram.programId,
+  });
+
+  // Token-2022 resolves the hook's blacklist PDAs from the ExtraAccountMetaList
+  const transfer = (source: PublicKey, destination: PublicKey, owner: Keypair, amount: number) =>
+    transferCheckedWithTransferHook(
+      provider.connection,
+      authority.payer,
+      source,
+      mint.publicKey,
+      destination,
+      owner,
+      BigInt(amount),
+      9,
+      [],
+      { commitment: 'confirmed' },
+      TOKEN_2022_PROGRAM_ID
+    );
+
+  // Anchor events emitted by a confirmed transaction
+  const eventsOf = async (signature: string) => {
+    const txInfo = await provider.connection.getTransaction(signature, {
+      commitment: 'confirmed',
+      maxSupportedTransactionVersion: 0,
+    });
+    const parser = new anchor.EventParser(program.programId, program.coder);
+    return [...parser.parseLogs(txInfo?.meta?.logMessages ?? [])];
+  };
+
+  // Fails unless `tx` is rejected with the program error `code`
+  const proposalPda = (actionId: number) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from('proposal'), stablecoinConfig.toBuffer(), new anchor.BN(actionId).toArrayLike(Buffer, 'le', 8)],
+      program.programId
+    )[0];
+
+  // Returns the id of the queued action
+  const proposeAction = async (action: object) => {
+    const { proposalCount } = await program.account.stablecoinConfig.fetch(stablecoinConfig);
+    const actionId = proposalCount.toNumber();
+    await program.methods
+      .proposeAction(action)
+      .accounts({
+        proposedAction: proposalPda(actionId),
+        stablecoinConfig,
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    return actionId;
+  };
+
+  const executeAction = (actionId: number, roleGrant: PublicKey | null = null) =>
+    program.methods
+      .executeAction(new anchor.BN(actionId))
+      .accounts({
+        proposedAction: proposalPda(actionId),
+        stablecoinConfig,
+        mint: mint.publicKey,
+        minterInfo: null,
+        roleGrant,
+        treasury: null,
+        payer: authority.publicKey,
+        authority: authority.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+  const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+  const expectError = async (tx: Promise<unknown>, code: string) => {
+    try {
+      await tx;
+    } catch (error: any) {
+      const logs: string[] = error.logs ?? [];
+      assert.include([error.toString(), ...logs].join('\n'), code);
+      return;
+    }
+    assert.fail(`Expected the transaction to fail with ${code}`);
+  };
+
   before(async () => {
     console.log('Setting up test environment...');
 
-    // Create a new token mint
+    // The mint is created by `initialize`
     mint = Keypair.generate();
 
     // Find PDAs
@@ -48,32 +473,108 @@ describe('Solana Stablecoin Standard - Integration Tests', () => {
       program.programId
     );
 
-    [mintAuthority] = PublicKey.findProgramAddressSync(
-      [Buffer.from('authority'), Buffer.from('mint'), mint.publicKey.toBuffer()],
+    console.log('Test environment setup complete');
+  });
+
+  it('Rejects more than 9 decimals', async () => {
+    console.log('\n=== TEST: Invalid Decimals ===');
+
+    const badMint = Keypair.generate();
+    const [badConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from('stablecoin'), badMint.publicKey.toBuffer()],
       program.programId
     );
 
-    [freezeAuthority] = PublicKey.findProgramAddressSync(
-      [Buffer.from('authority'), Buffer.from('freeze'), mint.publicKey.toBuffer()],
+    await expectError(
+      program.methods
+        .initialize('Bad Decimals', 'BAD', 'https://example.com/metadata.json', 10, { blacklist: {} }, false, null, null, null, RESERVE_STALENESS_SECONDS)
+        .accounts({
+          stablecoinConfig: badConfig,
+          mint: badMint.publicKey,
+          rateHistory: null,
+          complianceLog: PublicKey.findProgramAddressSync(
+            [Buffer.from('compliance_log'), badConfig.toBuffer()],
+            program.programId
+          )[0],
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([badMint])
+        .rpc(),
+      'InvalidDecimals'
+    );
+
+    console.log('✅ Invalid decimals rejected');
+  });
+
+  it('Rejects a reserve staleness window that is not positive', async () => {
+    const badMint = Keypair.generate();
+    const [badConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from('stablecoin'), badMint.publicKey.toBuffer()],
       program.programId
     );
 
-    // Create token accounts
-    userTokenAccount = await createAccount(
-      provider.connection,
-      authority.payer,
-      mint.publicKey,
-      authority.publicKey
+    await expectError(
+      program.methods
+        .initialize('No Reserves', 'NRS', 'https://example.com/metadata.json', 6, { blacklist: {} }, false, null, null, null, new anchor.BN(0))
+        .accounts({
+          stablecoinConfig: badConfig,
+          mint: badMint.publicKey,
+          rateHistory: null,
+          complianceLog: PublicKey.findProgramAddressSync(
+            [Buffer.from('compliance_log'), badConfig.toBuffer()],
+            program.programId
+          )[0],
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([badMint])
+        .rpc(),
+      'InvalidReserveStaleness'
     );
+  });
 
-    treasuryAccount = await createAccount(
-      provider.connection,
-      authority.payer,
-      mint.publicKey,
-      authority.publicKey
+  it('Seeds the rate history of an interest-bearing mint', async () => {
+    const rateMint = Keypair.generate();
+    const [rateConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from('stablecoin'), rateMint.publicKey.toBuffer()],
+      program.programId
+    );
+    const [rateHistory] = PublicKey.findProgramAddressSync(
+      [Buffer.from('rate_history'), rateConfig.toBuffer()],
+      program.programId
     );
+    const initialize = (history: PublicKey | null) =>
+      program.methods
+        .initialize('Yield Coin', 'YLD', 'https://example.com/metadata.json', 6, { blacklist: {} }, false, null, null, 500, RESERVE_STALENESS_SECONDS)
+        .accounts({
+          stablecoinConfig: rateConfig,
+          mint: rateMint.publicKey,
+          rateHistory: history,
+          complianceLog: PublicKey.findProgramAddressSync(
+            [Buffer.from('compliance_log'), rateConfig.toBuffer()],
+            program.programId
+          )[0],
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([rateMint])
+        .rpc();
 
-    console.log('Test environment setup complete');
+    await expectError(initialize(null), 'InvalidRateHistory');
+    await initialize(rateHistory);
+
+    // The initial rate is the first record, before any `update_rate`
+    const history = await program.account.rateHistory.fetch(rateHistory);
+    assert.equal(history.totalUpdates.toNumber(), 1);
+    assert.equal(history.records[0].rate, 500);
+    assert.equal(history.records[0].updatedBy.toString(), authority.publicKey.toString());
   });
 
   it('Initializes the stablecoin', async () => {
@@ -86,23 +587,47 @@ describe('Solana Stablecoin Standard - Integration Tests', () => {
           'TST',
           'https://example.com/metadata.json',
           9,
-          true,  // enable_permanent_delegate
-          true,  // enable_transfer_hook
-          false  // default_account_frozen
+          { blacklist: {} },
+          false,
+          null, // no auditor key, so no confidential transfers
+          null, // no transfer fee
+          null, // not interest-bearing
+          RESERVE_STALENESS_SECONDS
         )
         .accounts({
           stablecoinConfig,
           mint: mint.publicKey,
-          mintAuthority,
-          freezeAuthority,
+          rateHistory: null,
+          complianceLog: complianceLogPda(),
           authority: authority.publicKey,
-          payer: authority.payer.publicKey,
+          payer: authority.publicKey,
           systemProgram: SystemProgram.programId,
-          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
         })
-        .signers([mint, authority.payer])
+        .signers([mint])
         .rpc();
 
+      const [extraAccountMetaList] = PublicKey.findProgramAddressSync(
+        [Buffer.from('extra-account-metas'), mint.publicKey.toBuffer()],
+        program.programId
+      );
+      const hookTx = await program.methods
+        .initializeExtraAccountMetaList()
+        .accounts({
+          extraAccountMetaList,
+          mint: mint.publicKey,
+          stablecoinConfig,
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc({ commitment: 'confirmed' });
+
+      const [hookEvent] = await eventsOf(hookTx);
+      assert.equal(hookEvent.name, 'TransferHookInitialized');
+      assert.equal(hookEvent.data.mint.toString(), mint.publicKey.toString());
+      assert.equal(hookEvent.data.actor.toString(), authority.publicKey.toString());
+
       console.log('✅ Stablecoin initialized');
       console.log('Transaction:', tx);
 
@@ -111,6 +636,49 @@ describe('Solana Stablecoin Standard - Integration Tests', () => {
       console.log('Config:', config);
       assert.equal(config.symbol, 'TST');
       assert.equal(config.decimals, 9);
+      assert.equal(config.masterAdmin.toString(), authority.publicKey.toString());
+
+      // The config PDA holds the token authorities, not the admin key
+      const mintInfo = await getMint(provider.connection, mint.publicKey, 'confirmed', TOKEN_2022_PROGRAM_ID);
+      assert.equal(mintInfo.mintAuthority?.toString(), stablecoinConfig.toString());
+      assert.equal(mintInfo.freezeAuthority?.toString(), stablecoinConfig.toString());
+
+      // Wallets read the name and symbol from the on-mint TokenMetadata
+      const metadata = await getTokenMetadata(provider.connection, mint.publicKey, 'confirmed', TOKEN_2022_PROGRAM_ID);
+      assert.equal(metadata?.name, 'Test Stablecoin');
+      assert.equal(metadata?.symbol, 'TST');
+      assert.equal(metadata?.updateAuthority?.toString(), stablecoinConfig.toString());
+
+      // Token accounts can only be created once the mint exists
+      userTokenAccount = await createAccount(
+        provider.connection,
+        authority.payer,
+        mint.publicKey,
+        authority.publicKey,
+        Keypair.generate(),
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      holderTokenAccount = await createAccount(
+        provider.connection,
+        authority.payer,
+        mint.publicKey,
+        holder.publicKey,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      treasuryAccount = await createAccount(
+        provider.connection,
+        authority.payer,
+        mint.publicKey,
+        authority.publicKey,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
 
     } catch (error) {
       console.error('❌ Initialize failed:', error);
@@ -118,6 +686,97 @@ describe('Solana Stablecoin Standard - Integration Tests', () => {
     }
   });
 
+  it('Grants the operational roles and a minter quota', async () => {
+    console.log('\n=== TEST: Grant Roles ===');
+
+    try {
+      for (const role of [
+        Role.MasterMinter,
+        Role.Minter,
+        Role.Burner,
+        Role.Pauser,
+        Role.Blacklister,
+        Role.Seizer,
+        Role.Attestor,
+      ]) {
+        await grantRole(role, authority.publicKey);
+      }
+
+      // 10 tokens, no rolling cap
+      await program.methods
+        .configureMinter(authority.publicKey, new anchor.BN(10_000_000_000), new anchor.BN(0))
+        .accounts({
+          minterInfo: minterPda(authority.publicKey),
+          stablecoinConfig,
+          masterMinterRole: rolePda(Role.MasterMinter, authority.publicKey),
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      console.log('✅ Roles and quota granted');
+
+      const grant = await program.account.roleGrant.fetch(rolePda(Role.Minter, authority.publicKey));
+      assert.equal(grant.holder.toString(), authority.publicKey.toString());
+
+      const minterInfo = await program.account.minterInfo.fetch(minterPda(authority.publicKey));
+      assert.equal(minterInfo.allowance.toString(), '10000000000');
+
+    } catch (error) {
+      console.error('❌ Grant roles failed:', error);
+      throw error;
+    }
+  });
+
+  it('Only mints within a fresh reserve attestation', async () => {
+    console.log('\n=== TEST: Proof of Reserves ===');
+
+    const amount = new anchor.BN(1_000_000_000);
+    const now = (await provider.connection.getBlockTime(await provider.connection.getSlot()))!;
+
+    try {
+      // No attestation yet
+      await expectError(
+        program.methods.mintTo(amount).accounts(mintAccounts(userTokenAccount)).rpc(),
+        'InsufficientReserves'
+      );
+
+      // An hour-old report that does not cover the mint
+      await attestReserves(amount.subn(1), now - 3600);
+      await expectError(
+        program.methods.mintTo(amount).accounts(mintAccounts(userTokenAccount)).rpc(),
+        'InsufficientReserves'
+      );
+
+      // Older than a one-minute window
+      await setReserveStaleness(60);
+      await expectError(
+        program.methods.mintTo(amount).accounts(mintAccounts(userTokenAccount)).rpc(),
+        'StaleReserveAttestation'
+      );
+
+      // The age check cannot be switched off
+      await expectError(setReserveStaleness(0), 'InvalidReserveStaleness');
+      await setReserveStaleness(RESERVE_STALENESS_SECONDS.toNumber());
+
+      // Reports cannot go backwards
+      await expectError(attestReserves(amount, now - 7200), 'InvalidAttestation');
+
+      // 1000 tokens of reserves for the rest of the suite
+      await attestReserves(new anchor.BN(1_000_000_000_000), now);
+      const attestation = await program.account.reserveAttestation.fetch(reservesPda());
+      assert.equal(attestation.reserves.toString(), '1000000000000');
+      assert.equal(attestation.attestor.toString(), authority.publicKey.toString());
+
+      console.log('✅ Mints gated on the reserve attestation');
+
+    } catch (error) {
+      console.error('❌ Proof of reserves failed:', error);
+      throw error;
+    }
+  });
+
   it('Mints tokens to a recipient', async () => {
     console.log('\n=== TEST: Mint Tokens ===');
 
@@ -126,47 +785,80 @@ describe('Solana Stablecoin Standard - Integration Tests', () => {
 
       const tx = await program.methods
         .mintTo(amount)
-        .accounts({
-          mint: mint.publicKey,
-          mintAuthority,
-          to: userTokenAccount,
-          payer: authority.payer.publicKey,
-          tokenProgram: TOKEN_PROGRAM_ID,
-        })
-        .signers([authority.payer])
-        .rpc();
+        .accounts(mintAccounts(userTokenAccount))
+        .rpc({ commitment: 'confirmed' });
 
       console.log('✅ Tokens minted');
       console.log('Transaction:', tx);
 
+      const [minted] = await eventsOf(tx);
+      assert.equal(minted.name, 'Minted');
+      assert.equal(minted.data.target.toString(), userTokenAccount.toString());
+      assert.equal(minted.data.amount.toString(), amount.toString());
+
       // Verify the balance
-      const account = await getAccount(provider.connection, userTokenAccount);
+      const account = await getAccount(provider.connection, userTokenAccount, 'confirmed', TOKEN_2022_PROGRAM_ID);
       console.log('Balance:', account.amount.toString());
       assert.equal(account.amount.toString(), amount.toString());
 
+      const config = await program.account.stablecoinConfig.fetch(stablecoinConfig);
+      assert.equal(config.totalMinted.toString(), amount.toString());
+      assert.equal(config.circulatingSupply.toString(), amount.toString());
+
+      const minterInfo = await program.account.minterInfo.fetch(minterPda(authority.publicKey));
+      assert.equal(minterInfo.allowance.toString(), '9000000000');
+
     } catch (error) {
       console.error('❌ Mint failed:', error);
       throw error;
     }
   });
 
+  it('Rejects a mint above the minter allowance', async () => {
+    console.log('\n=== TEST: Minter Allowance ===');
+
+    await expectError(
+      program.methods
+        .mintTo(new anchor.BN(10_000_000_000))
+        .accounts(mintAccounts(userTokenAccount))
+        .rpc(),
+      'MinterAllowanceExceeded'
+    );
+
+    console.log('✅ Mint above the allowance rejected');
+  });
+
   it('Freezes a token account', async () => {
     console.log('\n=== TEST: Freeze Account ===');
 
     try {
       const tx = await program.methods
-        .freezeAccount()
+        .freezeAccount(1, Array(32).fill(7))
         .accounts({
+          stablecoinConfig,
           mint: mint.publicKey,
           tokenAccount: userTokenAccount,
-          freezeAuthority,
-          tokenProgram: TOKEN_PROGRAM_ID,
+          complianceLog: complianceLogPda(),
+          blacklisterRole: rolePda(Role.Blacklister, authority.publicKey),
+          authority: authority.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
         })
         .rpc();
 
       console.log('✅ Account frozen');
       console.log('Transaction:', tx);
 
+      const account = await getAccount(provider.connection, userTokenAccount, 'confirmed', TOKEN_2022_PROGRAM_ID);
+      assert.isTrue(account.isFrozen);
+
+      // Recorded in the compliance audit trail
+      const record = await lastComplianceRecord();
+      assert.equal(record.action, 2); // ComplianceAction::Freeze
+      assert.equal(record.actor.toString(), authority.publicKey.toString());
+      assert.equal(record.target.toString(), userTokenAccount.toString());
+      assert.equal(record.reasonCode, 1);
+      assert.deepEqual(record.caseReference, Array(32).fill(7));
+
     } catch (error) {
       console.error('❌ Freeze failed:', error);
       throw error;
@@ -180,16 +872,22 @@ describe('Solana Stablecoin Standard - Integration Tests', () => {
       const tx = await program.methods
         .thawAccount()
         .accounts({
+          stablecoinConfig,
           mint: mint.publicKey,
           tokenAccount: userTokenAccount,
-          freezeAuthority,
-          tokenProgram: TOKEN_PROGRAM_ID,
+          complianceLog: complianceLogPda(),
+          blacklisterRole: rolePda(Role.Blacklister, authority.publicKey),
+          authority: authority.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
         })
         .rpc();
 
       console.log('✅ Account thawed');
       console.log('Transaction:', tx);
 
+      const account = await getAccount(provider.connection, userTokenAccount, 'confirmed', TOKEN_2022_PROGRAM_ID);
+      assert.isFalse(account.isFrozen);
+
     } catch (error) {
       console.error('❌ Thaw failed:', error);
       throw error;
@@ -200,23 +898,12 @@ describe('Solana Stablecoin Standard - Integration Tests', () => {
     console.log('\n=== TEST: Add to Blacklist ===');
 
     const addressToBlacklist = Keypair.generate().publicKey;
-
-    [blacklistEntry] = PublicKey.findProgramAddressSync(
-      [Buffer.from('blacklist'), addressToBlacklist.toBuffer()],
-      program.programId
-    );
+    blacklistEntry = blacklistPda(addressToBlacklist);
 
     try {
       const tx = await program.methods
-        .addToBlacklist(addressToBlacklist)
-        .accounts({
-          blacklistEntry,
-          stablecoinConfig,
-          authority: authority.publicKey,
-          payer: authority.payer.publicKey,
-          systemProgram: SystemProgram.programId,
-        })
-        .signers([authority.payer])
+        .addToBlacklist(addressToBlacklist, 1, Array(32).fill(9))
+        .accounts(modifyBlacklistAccounts(addressToBlacklist))
         .rpc();
 
       console.log('✅ Address added to blacklist');
@@ -226,6 +913,8 @@ describe('Solana Stablecoin Standard - Integration Tests', () => {
       const entry = await program.account.blacklistEntry.fetch(blacklistEntry);
       console.log('Blacklist entry:', entry);
       assert.equal(entry.isBlacklisted, true);
+      assert.equal(entry.reasonCode, 1);
+      assert.deepEqual(entry.caseReference, Array(32).fill(9));
 
     } catch (error) {
       console.error('❌ Add to blacklist failed:', error);
@@ -237,37 +926,19 @@ describe('Solana Stablecoin Standard - Integration Tests', () => {
     console.log('\n=== TEST: Remove from Blacklist ===');
 
     const addressToRemove = Keypair.generate().publicKey;
-
-    [blacklistEntry] = PublicKey.findProgramAddressSync(
-      [Buffer.from('blacklist'), addressToRemove.toBuffer()],
-      program.programId
-    );
+    blacklistEntry = blacklistPda(addressToRemove);
 
     try {
       // First add to blacklist
       await program.methods
-        .addToBlacklist(addressToRemove)
-        .accounts({
-          blacklistEntry,
-          stablecoinConfig,
-          authority: authority.publicKey,
-          payer: authority.payer.publicKey,
-          systemProgram: SystemProgram.programId,
-        })
-        .signers([authority.payer])
+        .addToBlacklist(addressToRemove, null, null)
+        .accounts(modifyBlacklistAccounts(addressToRemove))
         .rpc();
 
       // Then remove
       const tx = await program.methods
         .removeFromBlacklist(addressToRemove)
-        .accounts({
-          blacklistEntry,
-          stablecoinConfig,
-          authority: authority.publicKey,
-          payer: authority.payer.publicKey,
-          systemProgram: SystemProgram.programId,
-        })
-        .signers([authority.payer])
+        .accounts(removeBlacklistAccounts(addressToRemove))
         .rpc();
 
       console.log('✅ Address removed from blacklist');
@@ -284,32 +955,556 @@ describe('Solana Stablecoin Standard - Integration Tests', () => {
     }
   });
 
-  it('Seizes tokens from a blacklisted account', async () => {
-    console.log('\n=== TEST: Seize Tokens ===');
+  it('Refuses to remove an address that was never blacklisted', async () => {
+    const neverBlacklisted = Keypair.generate().publicKey;
+
+    // Removal no longer creates an entry, so there is nothing to clear
+    await expectError(
+      program.methods
+        .removeFromBlacklist(neverBlacklisted)
+        .accounts(removeBlacklistAccounts(neverBlacklisted))
+        .rpc(),
+      'AccountNotInitialized'
+    );
+    assert.isNull(await provider.connection.getAccountInfo(blacklistPda(neverBlacklisted)));
+  });
+
+  it('Closes a cleared blacklist entry into the rent collector', async () => {
+    console.log('\n=== TEST: Close Blacklist Entry ===');
+
+    const address = Keypair.generate().publicKey;
+    const rentCollector = Keypair.generate().publicKey;
+    const closeAccounts = {
+      blacklistEntry: blacklistPda(address),
+      stablecoinConfig,
+      mint: mint.publicKey,
+      rentCollector,
+      blacklisterRole: rolePda(Role.Blacklister, authority.publicKey),
+      authority: authority.publicKey,
+    };
+
+    await program.methods
+      .setRentCollector(rentCollector)
+      .accounts({ stablecoinConfig, authority: authority.publicKey })
+      .rpc();
+    await program.methods
+      .addToBlacklist(address, null, null)
+      .accounts(modifyBlacklistAccounts(address))
+      .rpc();
+
+    // A live entry cannot be closed
+    await expectError(
+      program.methods.closeBlacklistEntry(address).accounts(closeAccounts).rpc(),
+      'AddressBlacklisted'
+    );
+
+    await program.methods
+      .removeFromBlacklist(address)
+      .accounts(removeBlacklistAccounts(address))
+      .rpc();
+    const rent = (await provider.connection.getAccountInfo(blacklistPda(address)))!.lamports;
+
+    await program.methods.closeBlacklistEntry(address).accounts(closeAccounts).rpc();
+
+    assert.isNull(await provider.connection.getAccountInfo(blacklistPda(address)));
+    assert.equal(await provider.connection.getBalance(rentCollector), rent);
+
+    await program.methods
+      .setRentCollector(authority.publicKey)
+      .accounts({ stablecoinConfig, authority: authority.publicKey })
+      .rpc();
+    console.log('✅ Rent reclaimed');
+  });
+
+  it('Rejects transfers to or from a blacklisted owner in the transfer hook', async () => {
+    console.log('\n=== TEST: Transfer Hook Blacklist ===');
+
+    try {
+      // Blacklisted destination owner
+      await program.methods
+        .addToBlacklist(holder.publicKey, null, null)
+        .accounts(modifyBlacklistAccounts(holder.publicKey))
+        .rpc();
+      await expectError(
+        transfer(userTokenAccount, holderTokenAccount, authority.payer, 100000000),
+        'AddressBlacklisted'
+      );
+
+      // Once removed, the same transfer goes through
+      await program.methods
+        .removeFromBlacklist(holder.publicKey)
+        .accounts(removeBlacklistAccounts(holder.publicKey))
+        .rpc();
+      await transfer(userTokenAccount, holderTokenAccount, authority.payer, 100000000);
+
+      // Blacklisted source owner
+      await program.methods
+        .addToBlacklist(authority.publicKey, null, null)
+        .accounts(modifyBlacklistAccounts(authority.publicKey))
+        .rpc();
+      await expectError(
+        transfer(userTokenAccount, holderTokenAccount, authority.payer, 100000000),
+        'AddressBlacklisted'
+      );
+      await program.methods
+        .removeFromBlacklist(authority.publicKey)
+        .accounts(removeBlacklistAccounts(authority.publicKey))
+        .rpc();
+
+      console.log('✅ Blacklisted transfers rejected');
+
+      const holderAccount = await getAccount(provider.connection, holderTokenAccount, 'confirmed', TOKEN_2022_PROGRAM_ID);
+      assert.equal(holderAccount.amount.toString(), '100000000');
+
+    } catch (error) {
+      console.error('❌ Transfer hook test failed:', error);
+      throw error;
+    }
+  });
+
+  it('Burns tokens and tracks the supply counters', async () => {
+    console.log('\n=== TEST: Burn Tokens ===');
 
     try {
-      const amount = new anchor.BN(500000000); // 0.5 tokens
+      // Holder redemption from the authority's own account
+      await program.methods
+        .burn(new anchor.BN(100000000))
+        .accounts({ ...burnAccounts(userTokenAccount), owner: authority.publicKey })
+        .rpc();
 
+      // Issuer redemption against an approved delegate
+      await approve(
+        provider.connection,
+        authority.payer,
+        holderTokenAccount,
+        authority.publicKey,
+        holder,
+        50000000,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
       const tx = await program.methods
-        .seizeTokens(amount)
+        .burnFrom(new anchor.BN(50000000))
         .accounts({
-          mint: mint.publicKey,
-          from: userTokenAccount,
-          fromAuthority: authority.payer.publicKey,
-          treasury: treasuryAccount,
-          freezeAuthority,
-          tokenProgram: TOKEN_PROGRAM_ID,
+          ...burnAccounts(holderTokenAccount),
+          burnerRole: rolePda(Role.Burner, authority.publicKey),
+          authority: authority.publicKey,
         })
         .rpc();
 
+      console.log('✅ Tokens burned');
+      console.log('Transaction:', tx);
+
+      const config = await program.account.stablecoinConfig.fetch(stablecoinConfig);
+      assert.equal(config.totalBurned.toString(), '150000000');
+      assert.equal(config.circulatingSupply.toString(), '850000000');
+
+    } catch (error) {
+      console.error('❌ Burn failed:', error);
+      throw error;
+    }
+  });
+
+  it('Mints approved requests and refuses to resolve them twice', async () => {
+    console.log('\n=== TEST: Mint Requests ===');
+
+    try {
+      const mintedId = await requestMint(userTokenAccount, 1000);
+      const before = await getAccount(provider.connection, userTokenAccount, 'confirmed', TOKEN_2022_PROGRAM_ID);
+      await approveMintRequest(mintedId, userTokenAccount);
+      const after = await getAccount(provider.connection, userTokenAccount, 'confirmed', TOKEN_2022_PROGRAM_ID);
+      assert.equal((after.amount - before.amount).toString(), '1000');
+
+      const minted = await program.account.mintRequest.fetch(requestPda('mint_request', mintedId));
+      assert.deepEqual(minted.status, { approved: {} });
+      assert.equal(minted.resolvedBy.toString(), authority.publicKey.toString());
+
+      // An executed request cannot mint again
+      await expectError(approveMintRequest(mintedId, userTokenAccount), 'RequestNotPending');
+
+      // Neither can a rejected one
+      const rejectedId = await requestMint(userTokenAccount, 1000);
+      await program.methods
+        .rejectMintRequest(new anchor.BN(rejectedId))
+        .accounts({
+          mintRequest: requestPda('mint_request', rejectedId),
+          stablecoinConfig,
+          minterRole: rolePda(Role.Minter, authority.publicKey),
+          authority: authority.publicKey,
+        })
+        .rpc();
+      await expectError(approveMintRequest(rejectedId, userTokenAccount), 'RequestNotPending');
+
+      console.log('✅ Mint requests approved and rejected once');
+
+    } catch (error) {
+      console.error('❌ Mint requests failed:', error);
+      throw error;
+    }
+  });
+
+  it('Re-checks the owner and blacklist when approving a redemption', async () => {
+    console.log('\n=== TEST: Redemption Requests ===');
+
+    try {
+      const source = await createAccount(
+        provider.connection,
+        authority.payer,
+        mint.publicKey,
+        authority.publicKey,
+        Keypair.generate(),
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await approveMintRequest(await requestMint(source, 3000), source);
+
+      // Blacklisted after filing
+      const blacklistedId = await requestRedemption(source, 1000);
+      await program.methods
+        .addToBlacklist(authority.publicKey, null, null)
+        .accounts(modifyBlacklistAccounts(authority.publicKey))
+        .rpc();
+      await expectError(approveRedemptionRequest(blacklistedId, source), 'AddressBlacklisted');
+      await expectError(requestRedemption(source, 1000), 'AddressBlacklisted');
+      await program.methods
+        .removeFromBlacklist(authority.publicKey)
+        .accounts(removeBlacklistAccounts(authority.publicKey))
+        .rpc();
+
+      await approveRedemptionRequest(blacklistedId, source);
+      let account = await getAccount(provider.connection, source, 'confirmed', TOKEN_2022_PROGRAM_ID);
+      assert.equal(account.amount.toString(), '2000');
+
+      // The account changed hands after the request was filed
+      const transferredId = await requestRedemption(source, 1000);
+      await setAuthority(
+        provider.connection,
+        authority.payer,
+        source,
+        authority.payer,
+        AuthorityType.AccountOwner,
+        holder.publicKey,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await expectError(approveRedemptionRequest(transferredId, source), 'UnauthorizedAccess');
+
+      account = await getAccount(provider.connection, source, 'confirmed', TOKEN_2022_PROGRAM_ID);
+      assert.equal(account.amount.toString(), '2000');
+      console.log('✅ Redemptions re-checked at approval');
+
+    } catch (error) {
+      console.error('❌ Redemption requests failed:', error);
+      throw error;
+    }
+  });
+
+  it('Rejects mints, burns, seizures and transfers while paused', async () => {
+    console.log('\n=== TEST: Pause ===');
+
+    await program.methods.pause().accounts(setPausedAccounts()).rpc();
+    console.log('✅ Paused');
+
+    try {
+      await expectError(
+        program.methods
+          .mintTo(new anchor.BN(1000))
+          .accounts(mintAccounts(userTokenAccount))
+          .rpc(),
+        'Paused'
+      );
+      await expectError(
+        program.methods
+          .burn(new anchor.BN(1000))
+          .accounts({ ...burnAccounts(userTokenAccount), owner: authority.publicKey })
+          .rpc(),
+        'Paused'
+      );
+      await expectError(seize(userTokenAccount, authority.publicKey, 1000), 'Paused');
+      // The hook reads the pause flag from the config in the ExtraAccountMetaList
+      await expectError(transfer(userTokenAccount, holderTokenAccount, authority.payer, 1000), 'Paused');
+
+      console.log('✅ Paused operations rejected');
+    } finally {
+      await program.methods.unpause().accounts(setPausedAccounts()).rpc();
+    }
+
+    // Transfers resume once unpaused
+    await transfer(userTokenAccount, holderTokenAccount, authority.payer, 1000);
+    const config = await program.account.stablecoinConfig.fetch(stablecoinConfig);
+    assert.isFalse(config.paused);
+  });
+
+  it('Blacklists and unblacklists addresses in a batch', async () => {
+    console.log('\n=== TEST: Batch Blacklist ===');
+
+    const addresses = [0, 1, 2].map(() => Keypair.generate().publicKey);
+
+    try {
+      await program.methods
+        .batchAddToBlacklist(addresses, 3, null)
+        .accounts(batchBlacklistAccounts())
+        .remainingAccounts(addresses.map((address) => writable(blacklistPda(address))))
+        .rpc();
+
+      for (const address of addresses) {
+        const entry = await program.account.blacklistEntry.fetch(blacklistPda(address));
+        assert.isTrue(entry.isBlacklisted);
+        assert.equal(entry.reasonCode, 3);
+      }
+
+      await program.methods
+        .batchRemoveFromBlacklist(addresses)
+        .accounts(batchBlacklistAccounts())
+        .remainingAccounts(addresses.map((address) => writable(blacklistPda(address))))
+        .rpc();
+
+      for (const address of addresses) {
+        const entry = await program.account.blacklistEntry.fetch(blacklistPda(address));
+        assert.isFalse(entry.isBlacklisted);
+      }
+
+      console.log('✅ Batch blacklist applied and lifted');
+
+    } catch (error) {
+      console.error('❌ Batch blacklist failed:', error);
+      throw error;
+    }
+  });
+
+  it('Rejects batches with mismatched accounts or an invalid size', async () => {
+    console.log('\n=== TEST: Batch Validation ===');
+
+    const [first, second] = [0, 1].map(() => Keypair.generate().publicKey);
+
+    // The PDA of another address
+    await expectError(
+      program.methods
+        .batchAddToBlacklist([first, second], null, null)
+        .accounts(batchBlacklistAccounts())
+        .remainingAccounts([writable(blacklistPda(first)), writable(blacklistPda(first))])
+        .rpc(),
+      'InvalidBlacklistEntry'
+    );
+    // One account short
+    await expectError(
+      program.methods
+        .batchAddToBlacklist([first, second], null, null)
+        .accounts(batchBlacklistAccounts())
+        .remainingAccounts([writable(blacklistPda(first))])
+        .rpc(),
+      'BatchAccountsMismatch'
+    );
+    // A token account other than the target
+    await expectError(batchFreeze([holderTokenAccount], [treasuryAccount]), 'InvalidTokenAccount');
+
+    // Batches hold 1 to 20 targets
+    await expectError(
+      program.methods
+        .batchAddToBlacklist([], null, null)
+        .accounts(batchBlacklistAccounts())
+        .rpc(),
+      'InvalidBatchSize'
+    );
+    const tooMany = Array.from({ length: 21 }, () => Keypair.generate().publicKey);
+    await expectError(
+      program.methods
+        .batchAddToBlacklist(tooMany, null, null)
+        .accounts(batchBlacklistAccounts())
+        .rpc(),
+      'InvalidBatchSize'
+    );
+
+    console.log('✅ Invalid batches rejected');
+  });
+
+  it('Freezes token accounts in a batch', async () => {
+    console.log('\n=== TEST: Batch Freeze ===');
+
+    const targets = [];
+    for (let i = 0; i < 2; i++) {
+      targets.push(
+        await createAccount(
+          provider.connection,
+          authority.payer,
+          mint.publicKey,
+          Keypair.generate().publicKey,
+          undefined,
+          undefined,
+          TOKEN_2022_PROGRAM_ID
+        )
+      );
+    }
+
+    try {
+      await batchFreeze(targets, targets);
+      // Already frozen accounts are skipped
+      await batchFreeze(targets, targets);
+
+      for (const target of targets) {
+        const account = await getAccount(provider.connection, target, 'confirmed', TOKEN_2022_PROGRAM_ID);
+        assert.isTrue(account.isFrozen);
+      }
+
+      console.log('✅ Batch freeze applied');
+
+    } catch (error) {
+      console.error('❌ Batch freeze failed:', error);
+      throw error;
+    }
+  });
+
+  it('Only lets allowlisted owners transact in allowlist mode', async () => {
+    console.log('\n=== TEST: Allowlist Mode ===');
+
+    const outsider = Keypair.generate();
+    const outsiderTokenAccount = await createAccount(
+      provider.connection,
+      authority.payer,
+      mint.publicKey,
+      outsider.publicKey,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    await grantRole(Role.Allowlister, authority.publicKey);
+    await program.methods
+      .addToAllowlist(authority.publicKey)
+      .accounts(modifyAllowlistAccounts(authority.publicKey))
+      .rpc();
+    await setComplianceMode({ allowlist: {} });
+
+    try {
+      await expectError(
+        transfer(userTokenAccount, outsiderTokenAccount, authority.payer, 1000),
+        'AddressNotAllowlisted'
+      );
+      await expectError(
+        program.methods
+          .mintTo(new anchor.BN(1000))
+          .accounts(mintAccounts(outsiderTokenAccount, outsider.publicKey))
+          .rpc(),
+        'AddressNotAllowlisted'
+      );
+
+      console.log('✅ Non-allowlisted wallet rejected');
+
+      await program.methods
+        .addToAllowlist(outsider.publicKey)
+        .accounts(modifyAllowlistAccounts(outsider.publicKey))
+        .rpc();
+      await transfer(userTokenAccount, outsiderTokenAccount, authority.payer, 1000);
+
+      const account = await getAccount(provider.connection, outsiderTokenAccount, 'confirmed', TOKEN_2022_PROGRAM_ID);
+      assert.equal(account.amount.toString(), '1000');
+    } finally {
+      await setComplianceMode({ blacklist: {} });
+    }
+  });
+
+  it('Thaws each token account of an approved holder', async () => {
+    console.log('\n=== TEST: Approve Holder ===');
+
+    const kycHolder = Keypair.generate();
+    const expiry = Math.floor(Date.now() / 1000) + 365 * 24 * 60 * 60;
+    const isFrozen = async (tokenAccount: PublicKey) =>
+      (await getAccount(provider.connection, tokenAccount, 'confirmed', TOKEN_2022_PROGRAM_ID)).isFrozen;
+
+    await setDefaultAccountState(true);
+
+    try {
+      // Two token accounts for the same owner, both created frozen
+      const firstAccount = await createAccount(
+        provider.connection,
+        authority.payer,
+        mint.publicKey,
+        kycHolder.publicKey,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const secondAccount = await createAccount(
+        provider.connection,
+        authority.payer,
+        mint.publicKey,
+        kycHolder.publicKey,
+        Keypair.generate(),
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.isTrue(await isFrozen(firstAccount));
+      assert.isTrue(await isFrozen(secondAccount));
+
+      await approveHolder(firstAccount, kycHolder.publicKey, expiry);
+      assert.isFalse(await isFrozen(firstAccount));
+      assert.isTrue(await isFrozen(secondAccount));
+
+      // Re-approving the same holder thaws the second account too
+      await approveHolder(secondAccount, kycHolder.publicKey, expiry);
+      assert.isFalse(await isFrozen(secondAccount));
+
+      console.log('✅ Both token accounts thawed');
+
+      // Blacklisted holders stay frozen
+      const sanctioned = Keypair.generate();
+      const sanctionedAccount = await createAccount(
+        provider.connection,
+        authority.payer,
+        mint.publicKey,
+        sanctioned.publicKey,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      await program.methods
+        .addToBlacklist(sanctioned.publicKey, null, null)
+        .accounts(modifyBlacklistAccounts(sanctioned.publicKey))
+        .rpc();
+
+      await expectError(approveHolder(sanctionedAccount, sanctioned.publicKey, expiry), 'AddressBlacklisted');
+      assert.isTrue(await isFrozen(sanctionedAccount));
+    } finally {
+      await setDefaultAccountState(false);
+    }
+  });
+
+  it('Seizes tokens from a blacklisted account', async () => {
+    console.log('\n=== TEST: Seize Tokens ===');
+
+    const amount = 50000000; // 0.05 tokens
+
+    try {
+      // Only blacklisted owners can be seized from
+      await expectError(seize(userTokenAccount, authority.publicKey, amount), 'AddressNotBlacklisted');
+
+      await program.methods
+        .addToBlacklist(holder.publicKey, null, null)
+        .accounts(modifyBlacklistAccounts(holder.publicKey))
+        .rpc();
+
+      const treasuryBefore = await getAccount(provider.connection, treasuryAccount, 'confirmed', TOKEN_2022_PROGRAM_ID);
+
+      // No signature from the holder
+      const tx = await seize(holderTokenAccount, holder.publicKey, amount);
+
       console.log('✅ Tokens seized');
       console.log('Transaction:', tx);
 
       // Verify the balance
-      const fromAccount = await getAccount(provider.connection, userTokenAccount);
-      const treasuryAcc = await getAccount(provider.connection, treasuryAccount);
+      const fromAccount = await getAccount(provider.connection, holderTokenAccount, 'confirmed', TOKEN_2022_PROGRAM_ID);
+      const treasuryAcc = await getAccount(provider.connection, treasuryAccount, 'confirmed', TOKEN_2022_PROGRAM_ID);
       console.log('From balance:', fromAccount.amount.toString());
       console.log('Treasury balance:', treasuryAcc.amount.toString());
+      assert.equal((treasuryAcc.amount - treasuryBefore.amount).toString(), amount.toString());
+      assert.isTrue(fromAccount.isFrozen);
+
+      const record = await lastComplianceRecord();
+      assert.equal(record.action, 4); // ComplianceAction::Seize
+      assert.equal(record.target.toString(), holderTokenAccount.toString());
+      assert.equal(record.amount.toString(), amount.toString());
 
     } catch (error) {
       console.error('❌ Seize failed:', error);
@@ -317,58 +1512,337 @@ describe('Solana Stablecoin Standard - Integration Tests', () => {
     }
   });
 
-  it('Updates mint authority', async () => {
-    console.log('\n=== TEST: Update Mint Authority ===');
+  it('Refuses confidential transfer settings without an auditor key', async () => {
+    await expectError(
+      program.methods
+        .configureAutoApprove(true)
+        .accounts({
+          stablecoinConfig,
+          mint: mint.publicKey,
+          authority: authority.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc(),
+      'ConfidentialTransfersDisabled'
+    );
+  });
 
-    const newAuthority = Keypair.generate().publicKey;
+  it('Sweeps fees only to the configured treasury', async () => {
+    const sweepAccounts = (treasury: PublicKey) => ({
+      stablecoinConfig,
+      mint: mint.publicKey,
+      treasury,
+      feeAdminRole: rolePda(Role.FeeAdmin, authority.publicKey),
+      authority: authority.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+    });
+
+    await grantRole(Role.FeeAdmin, authority.publicKey);
+    await program.methods
+      .setTreasury()
+      .accounts({
+        stablecoinConfig,
+        mint: mint.publicKey,
+        treasury: treasuryAccount,
+        authority: authority.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .rpc();
+
+    const config = await program.account.stablecoinConfig.fetch(stablecoinConfig);
+    assert.equal(config.treasury.toString(), treasuryAccount.toString());
+
+    // Any other destination is refused, even one owned by the fee admin
+    await expectError(
+      program.methods.harvestFees().accounts(sweepAccounts(userTokenAccount)).rpc(),
+      'InvalidTreasury'
+    );
+    await expectError(
+      program.methods
+        .withdrawWithheldFees()
+        .accounts(sweepAccounts(userTokenAccount))
+        .remainingAccounts([{ pubkey: holderTokenAccount, isSigner: false, isWritable: true }])
+        .rpc(),
+      'InvalidTreasury'
+    );
+  });
+
+  it('Updates the on-mint metadata', async () => {
+    console.log('\n=== TEST: Update Metadata ===');
 
     try {
+      await grantRole(Role.MetadataAdmin, authority.publicKey);
+
       const tx = await program.methods
-        .updateMinter(newAuthority)
+        .updateMetadata('Test Stablecoin v2', null, null, [{ key: 'issuer', value: 'Test Issuer' }])
         .accounts({
           stablecoinConfig,
+          mint: mint.publicKey,
+          metadataAdminRole: rolePda(Role.MetadataAdmin, authority.publicKey),
           authority: authority.publicKey,
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
         })
         .rpc();
 
-      console.log('✅ Mint authority updated');
+      console.log('✅ Metadata updated');
       console.log('Transaction:', tx);
 
-      // Verify the config
+      const metadata = await getTokenMetadata(provider.connection, mint.publicKey, 'confirmed', TOKEN_2022_PROGRAM_ID);
+      assert.equal(metadata?.name, 'Test Stablecoin v2');
+      assert.equal(metadata?.symbol, 'TST');
+      assert.deepEqual(metadata?.additionalMetadata, [['issuer', 'Test Issuer']]);
+
+      // The config copy stays in sync
       const config = await program.account.stablecoinConfig.fetch(stablecoinConfig);
-      console.log('New mint authority:', config.mintAuthority.toString());
-      assert.equal(config.mintAuthority.toString(), newAuthority.toString());
+      assert.equal(config.name, 'Test Stablecoin v2');
 
     } catch (error) {
-      console.error('❌ Update mint authority failed:', error);
+      console.error('❌ Update metadata failed:', error);
       throw error;
     }
   });
 
-  it('Updates freeze authority', async () => {
-    console.log('\n=== TEST: Update Freeze Authority ===');
+  it('Grants and revokes a role', async () => {
+    console.log('\n=== TEST: Grant and Revoke Role ===');
 
-    const newAuthority = Keypair.generate().publicKey;
+    const pauser = Keypair.generate().publicKey;
+    const pauserRole = rolePda(Role.Pauser, pauser);
 
     try {
+      await grantRole(Role.Pauser, pauser);
+
+      const grant = await program.account.roleGrant.fetch(pauserRole);
+      assert.equal(grant.holder.toString(), pauser.toString());
+
       const tx = await program.methods
-        .updateFreezer(newAuthority)
+        .revokeRole(roleArgs[Role.Pauser], pauser)
+        .accounts({
+          roleGrant: pauserRole,
+          stablecoinConfig,
+          authority: authority.publicKey,
+        })
+        .rpc();
+
+      console.log('✅ Role revoked');
+      console.log('Transaction:', tx);
+
+      // Revoking closes the role PDA
+      assert.isNull(await provider.connection.getAccountInfo(pauserRole));
+
+    } catch (error) {
+      console.error('❌ Grant/revoke role failed:', error);
+      throw error;
+    }
+  });
+
+  it('Queues sensitive operations behind the timelock', async () => {
+    console.log('\n=== TEST: Governance Timelock ===');
+
+    const guardian = Keypair.generate();
+    const pauser = Keypair.generate().publicKey;
+    const timelockSeconds = 2;
+
+    try {
+      await grantRole(Role.Guardian, guardian.publicKey);
+      await program.methods
+        .setTimelock(new anchor.BN(timelockSeconds))
+        .accounts({ stablecoinConfig, authority: authority.publicKey })
+        .rpc();
+
+      // Direct calls are refused while the timelock is on
+      await expectError(grantRole(Role.Pauser, pauser), 'TimelockRequired');
+      await expectError(
+        program.methods
+          .setRentCollector(pauser)
+          .accounts({ stablecoinConfig, authority: authority.publicKey })
+          .rpc(),
+        'TimelockRequired'
+      );
+      await expectError(setReserveStaleness(60), 'TimelockRequired');
+
+      const grantId = await proposeAction({ grantRole: { role: roleArgs[Role.Pauser], holder: pauser } });
+      await expectError(executeAction(grantId, rolePda(Role.Pauser, pauser)), 'TimelockNotExpired');
+
+      // A guardian drops a queued action before it executes
+      const collectorId = await proposeAction({ setRentCollector: { rentCollector: pauser } });
+      await program.methods
+        .cancelAction(new anchor.BN(collectorId))
+        .accounts({
+          proposedAction: proposalPda(collectorId),
+          stablecoinConfig,
+          payer: authority.publicKey,
+          guardianRole: rolePda(Role.Guardian, guardian.publicKey),
+          authority: guardian.publicKey,
+        })
+        .signers([guardian])
+        .rpc();
+      assert.isNull(await provider.connection.getAccountInfo(proposalPda(collectorId)));
+
+      await sleep((timelockSeconds + 1) * 1000);
+      await executeAction(grantId, rolePda(Role.Pauser, pauser));
+      const grant = await program.account.roleGrant.fetch(rolePda(Role.Pauser, pauser));
+      assert.equal(grant.holder.toString(), pauser.toString());
+
+      let config = await program.account.stablecoinConfig.fetch(stablecoinConfig);
+      assert.equal(config.rentCollector.toString(), authority.publicKey.toString());
+
+      // Turning the timelock off is itself a proposal
+      const timelockId = await proposeAction({ setTimelock: { timelockSeconds: new anchor.BN(0) } });
+      await sleep((timelockSeconds + 1) * 1000);
+      await executeAction(timelockId);
+
+      config = await program.account.stablecoinConfig.fetch(stablecoinConfig);
+      assert.equal(config.timelockSeconds.toNumber(), 0);
+      console.log('✅ Timelocked actions queued, cancelled and executed');
+
+    } catch (error) {
+      console.error('❌ Governance timelock failed:', error);
+      throw error;
+    }
+  });
+
+  it('Executes multisig proposals once they reach the threshold', async () => {
+    console.log('\n=== TEST: Admin Multisig ===');
+
+    const cosigner = Keypair.generate();
+    const newSigner = Keypair.generate().publicKey;
+    const [multisig] = PublicKey.findProgramAddressSync(
+      [Buffer.from('multisig'), stablecoinConfig.toBuffer()],
+      program.programId
+    );
+    const multisigProposalPda = (nonce: number) =>
+      PublicKey.findProgramAddressSync(
+        [Buffer.from('multisig_proposal'), multisig.toBuffer(), new anchor.BN(nonce).toArrayLike(Buffer, 'le', 8)],
+        program.programId
+      )[0];
+
+    try {
+      await program.methods
+        .createMultisig([authority.publicKey, cosigner.publicKey], 2)
+        .accounts({
+          multisig,
+          stablecoinConfig,
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      // The multisig PDA has to sign `set_multisig_signers`
+      const instruction = await program.methods
+        .setMultisigSigners([authority.publicKey, cosigner.publicKey, newSigner], 2)
+        .accounts({ multisig, stablecoinConfig })
+        .instruction();
+      const proposal = multisigProposalPda(0);
+      await program.methods
+        .createProposal(
+          instruction.keys.map((key) => ({ pubkey: key.pubkey, isSigner: key.isSigner, isWritable: key.isWritable })),
+          instruction.data
+        )
+        .accounts({
+          proposal,
+          multisig,
+          stablecoinConfig,
+          proposer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const execute = () =>
+        program.methods
+          .executeProposal(new anchor.BN(0))
+          .accounts({
+            proposal,
+            multisig,
+            stablecoinConfig,
+            proposer: authority.publicKey,
+            signer: authority.publicKey,
+          })
+          .remainingAccounts([
+            ...instruction.keys.map((key) => ({ ...key, isSigner: false })),
+            { pubkey: program.programId, isSigner: false, isWritable: false },
+          ])
+          .rpc();
+
+      // Only the proposer has approved so far
+      await expectError(execute(), 'NotEnoughApprovals');
+
+      await program.methods
+        .approveProposal(new anchor.BN(0))
+        .accounts({ proposal, multisig, stablecoinConfig, signer: cosigner.publicKey })
+        .signers([cosigner])
+        .rpc();
+      await execute();
+
+      const account = await program.account.adminMultisig.fetch(multisig);
+      assert.equal(account.signers.length, 3);
+      assert.equal(account.signers[2].toString(), newSigner.toString());
+      assert.equal(account.nonce.toNumber(), 1);
+
+      // Executed proposals are closed, so the nonce cannot be replayed
+      assert.isNull(await provider.connection.getAccountInfo(proposal));
+      await expectError(execute(), 'AccountNotInitialized');
+      console.log('✅ Multisig proposal executed once');
+
+    } catch (error) {
+      console.error('❌ Admin multisig failed:', error);
+      throw error;
+    }
+  });
+
+  it('Hands the master admin over', async () => {
+    console.log('\n=== TEST: Master Admin Handover ===');
+
+    const newAuthority = Keypair.generate();
+
+    try {
+      await program.methods
+        .proposeAuthority(newAuthority.publicKey)
         .accounts({
           stablecoinConfig,
           authority: authority.publicKey,
         })
         .rpc();
 
-      console.log('✅ Freeze authority updated');
+      // Nothing changes until the nominee accepts
+      let config = await program.account.stablecoinConfig.fetch(stablecoinConfig);
+      assert.equal(config.masterAdmin.toString(), authority.publicKey.toString());
+      assert.equal(config.pendingMasterAdmin.toString(), newAuthority.publicKey.toString());
+
+      // Only the nominee can accept
+      await expectError(
+        program.methods
+          .acceptAuthority()
+          .accounts({
+            stablecoinConfig,
+            newAuthority: authority.publicKey,
+          })
+          .rpc(),
+        'UnauthorizedAccess'
+      );
+
+      const tx = await program.methods
+        .acceptAuthority()
+        .accounts({
+          stablecoinConfig,
+          newAuthority: newAuthority.publicKey,
+        })
+        .signers([newAuthority])
+        .rpc();
+
+      console.log('✅ Master admin handed over');
       console.log('Transaction:', tx);
 
       // Verify the config
-      const config = await program.account.stablecoinConfig.fetch(stablecoinConfig);
-      console.log('New freeze authority:', config.freezeAuthority.toString());
-      assert.equal(config.freezeAuthority.toString(), newAuthority.toString());
+      config = await program.account.stablecoinConfig.fetch(stablecoinConfig);
+      console.log('New master admin:', config.masterAdmin.toString());
+      assert.equal(config.masterAdmin.toString(), newAuthority.publicKey.toString());
+      assert.isNull(config.pendingMasterAdmin);
 
     } catch (error) {
-      console.error('❌ Update freeze authority failed:', error);
+      console.error('❌ Master admin handover failed:', error);
       throw error;
     }
   });